@@ -11,6 +11,7 @@ import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogTrigger } from
 import { Separator } from "./ui/separator";
 import { Trash2, Edit, Plus, Download, Upload } from "lucide-react";
 import { toast } from "sonner@2.0.3";
+import { bcForModelChange } from "../lib/dragTables";
 
 interface EquipmentManagerProps {
   showInCalculator?: boolean;
@@ -599,13 +600,27 @@ export function EquipmentManager({ showInCalculator = false }: EquipmentManagerP
                   </div>
                   <div>
                     <Label htmlFor="drag-model">Drag Model</Label>
-                    <Select value={bulletForm.model} onValueChange={(value: ModelKind) => setBulletForm({...bulletForm, model: value})}>
+                    <Select
+                      value={bulletForm.model}
+                      onValueChange={(value: ModelKind) =>
+                        setBulletForm({
+                          ...bulletForm,
+                          model: value,
+                          bc: bcForModelChange(bulletForm.bc, bulletForm.model, value, bulletForm.V0),
+                        })
+                      }
+                    >
                       <SelectTrigger>
                         <SelectValue />
                       </SelectTrigger>
                       <SelectContent>
                         <SelectItem value="G1">G1</SelectItem>
+                        <SelectItem value="G2">G2</SelectItem>
+                        <SelectItem value="G5">G5</SelectItem>
+                        <SelectItem value="G6">G6</SelectItem>
                         <SelectItem value="G7">G7</SelectItem>
+                        <SelectItem value="G8">G8</SelectItem>
+                        <SelectItem value="GS">GS (sphere)</SelectItem>
                         <SelectItem value="noDrag">No Drag</SelectItem>
                       </SelectContent>
                     </Select>
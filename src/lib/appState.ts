@@ -1,5 +1,6 @@
 /* Types + defaults + persistence helpers. UI should call these. */
 import { enhancedStorage } from './indexedDB';
+import type { CdPoint, DsfPoint } from './dragTables';
 
 /* --------------------------------- Helpers for presets --------------------------------- */
 
@@ -36,7 +37,10 @@ export function applyBulletPreset(calculator: CalculatorState, preset: BulletPre
 
 /* -------------------------------------- Core types -------------------------------------- */
 
-export type ModelKind = "noDrag" | "G1" | "G7";
+// GL (flat-nose lead) is not offered: there is no verified GL table in
+// dragTables.ts yet, so a GL BC has to be converted to G1 by the user
+export type ModelKind = "noDrag" | "G1" | "G2" | "G5" | "G6" | "G7" | "G8" | "GS";
+export const MODEL_KINDS: ModelKind[] = ["G1", "G2", "G5", "G6", "G7", "G8", "GS", "noDrag"];
 export type ScopeUnits = "MIL" | "MOA";
 
 /** Snapshot of environment values */
@@ -45,6 +49,8 @@ export type Environment = {
   pressurehPa: number;
   humidityPct: number;
   altitudeM?: number;
+  /** optional; when set, the solver uses local gravity for this latitude */
+  latitudeDeg?: number;
 };
 
 /** A specific ammo profile tied to a weapon */
@@ -58,7 +64,27 @@ export type AmmoProfile = {
   V0: number;
   zeroDistanceM: number;
   scopeHeightMm: number;
-  mvTempSensitivity?: number;
+  mvTempSensitivity?: number;   // m/s per °C of powder temperature
+  /** temperature V0 was measured at; defaults to zeroEnv.temperatureC */
+  mvReferenceTempC?: number;
+  /** optional bullet geometry; enables stability and spin drift */
+  bulletLengthMm?: number;
+  bulletDiameterMm?: number;
+  /** optional moments of inertia (kg·m², spin axis / transverse about the
+   *  CG), e.g. from massProperties(); otherwise estimated from length */
+  ixKgM2?: number;
+  iyKgM2?: number;
+  /** optional measured Cd(Mach) curve; replaces bc/model (needs diameter) */
+  cdTable?: CdPoint[];
+  /** optional per-Mach drag scale factors from truing (sorted by Mach) */
+  dsfTable?: DsfPoint[];
+  /** where the group actually centres at zeroDistanceM (cm, + = high / right) */
+  zeroOffsetUpCm?: number;
+  zeroOffsetRightCm?: number;
+  /** wind on zeroing day (m/s, same angle convention as the calculator);
+   *  the windage zero absorbed its drift at zeroDistanceM */
+  zeroWindSpeed?: number;
+  zeroWindAngleDeg?: number;
   zeroEnv: Environment;         // IMPORTANT: always filled (migrated/defaulted)
   notes?: string;
   createdAt?: string;
@@ -258,11 +284,22 @@ function migrateWeapons(weapons: any[] | undefined): Weapon[] {
           ammoName: a?.ammoName ?? "",
           bulletWeightGr: Number.isFinite(a?.bulletWeightGr) ? a.bulletWeightGr : 175,
           bc: Number.isFinite(a?.bc) ? a.bc : 0.25,
-          model: (a?.model === "G1" || a?.model === "noDrag") ? a.model : "G7",
+          model: MODEL_KINDS.includes(a?.model) ? a.model : "G7",
           V0: Number.isFinite(a?.V0) ? a.V0 : 800,
           zeroDistanceM: Number.isFinite(a?.zeroDistanceM) ? a.zeroDistanceM : 100,
           scopeHeightMm: Number.isFinite(a?.scopeHeightMm) ? a.scopeHeightMm : 35,
           mvTempSensitivity: Number.isFinite(a?.mvTempSensitivity) ? a.mvTempSensitivity : undefined,
+          mvReferenceTempC: Number.isFinite(a?.mvReferenceTempC) ? a.mvReferenceTempC : undefined,
+          bulletLengthMm: Number.isFinite(a?.bulletLengthMm) ? a.bulletLengthMm : undefined,
+          bulletDiameterMm: Number.isFinite(a?.bulletDiameterMm) ? a.bulletDiameterMm : undefined,
+          ixKgM2: Number.isFinite(a?.ixKgM2) ? a.ixKgM2 : undefined,
+          iyKgM2: Number.isFinite(a?.iyKgM2) ? a.iyKgM2 : undefined,
+          cdTable: Array.isArray(a?.cdTable) ? a.cdTable : undefined,
+          dsfTable: Array.isArray(a?.dsfTable) ? a.dsfTable : undefined,
+          zeroOffsetUpCm: Number.isFinite(a?.zeroOffsetUpCm) ? a.zeroOffsetUpCm : undefined,
+          zeroOffsetRightCm: Number.isFinite(a?.zeroOffsetRightCm) ? a.zeroOffsetRightCm : undefined,
+          zeroWindSpeed: Number.isFinite(a?.zeroWindSpeed) ? a.zeroWindSpeed : undefined,
+          zeroWindAngleDeg: Number.isFinite(a?.zeroWindAngleDeg) ? a.zeroWindAngleDeg : undefined,
           zeroEnv: ensureZeroEnv(a?.zeroEnv),
           notes: a?.notes ?? undefined,
           createdAt: a?.createdAt ?? undefined,
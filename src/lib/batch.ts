@@ -0,0 +1,68 @@
+// src/lib/batch.ts
+import type { AmmoProfile, Environment } from "./appState";
+import { tryBuildDopeTable } from "./calcEngine";
+import type { SolveOptions, SolveResult, SolveRow } from "./calcEngine";
+
+/** One load/environment combination to solve */
+export type BatchInput = {
+  ammo: AmmoProfile;
+  env: Environment;
+  windSpeed?: number;
+  windAngleDeg?: number;
+  opts?: SolveOptions;
+};
+
+export type BatchResult = SolveResult<SolveRow[]>;
+
+/** Solve every input over the same ranges, in order, on the calling thread */
+export function solveBatch(inputs: BatchInput[], rangesM: number[]): BatchResult[] {
+  return inputs.map((b) =>
+    tryBuildDopeTable(b.ammo, b.env, rangesM, b.windSpeed ?? 0, b.windAngleDeg ?? 90, b.opts ?? {})
+  );
+}
+
+/**
+ * Same as solveBatch, spread over Web Workers so large grids (temperature ×
+ * DA, many loads) don't block the UI. Results come back in input order.
+ * Falls back to solveBatch where workers aren't available (SSR, tests).
+ * Functions can't be posted to a worker, so an input with opts.onStep is
+ * rejected up front: use solveBatch to observe steps. If any worker fails
+ * the others are terminated and the promise rejects.
+ */
+export async function solveBatchParallel(
+  inputs: BatchInput[],
+  rangesM: number[],
+  workers = typeof navigator !== "undefined" ? navigator.hardwareConcurrency || 4 : 4
+): Promise<BatchResult[]> {
+  const observed = inputs.findIndex((b) => b.opts?.onStep);
+  if (observed >= 0) {
+    throw new TypeError(`Input #${observed + 1} has opts.onStep, which can't run in a worker; use solveBatch`);
+  }
+  if (typeof Worker === "undefined" || inputs.length < 2) return solveBatch(inputs, rangesM);
+
+  const n = Math.max(1, Math.min(workers, inputs.length));
+  const chunk = Math.ceil(inputs.length / n);
+  const pool: Worker[] = [];
+  try {
+    const parts = await Promise.all(
+      Array.from({ length: n }, (_, k) => {
+        const slice = inputs.slice(k * chunk, (k + 1) * chunk);
+        if (!slice.length) return Promise.resolve([] as BatchResult[]);
+        return new Promise<BatchResult[]>((resolve, reject) => {
+          const w = new Worker(new URL("./batch.worker.ts", import.meta.url), { type: "module" });
+          pool.push(w);
+          w.onmessage = (e: MessageEvent<BatchResult[]>) => {
+            w.terminate();
+            resolve(e.data);
+          };
+          w.onerror = reject;
+          w.onmessageerror = reject;
+          w.postMessage({ inputs: slice, rangesM });
+        });
+      })
+    );
+    return parts.flat();
+  } finally {
+    pool.forEach((w) => w.terminate()); // after a failure the rest are still running
+  }
+}
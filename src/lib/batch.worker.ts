@@ -0,0 +1,7 @@
+// src/lib/batch.worker.ts
+import { solveBatch } from "./batch";
+import type { BatchInput } from "./batch";
+
+self.onmessage = (e: MessageEvent<{ inputs: BatchInput[]; rangesM: number[] }>) => {
+  self.postMessage(solveBatch(e.data.inputs, e.data.rangesM));
+};
@@ -1,22 +1,106 @@
-import type { AmmoProfile, Environment } from "./appState";
-import { fG1, fG7 } from "./dragTables";
+import type { AmmoProfile, Environment, ScopeUnits } from "./appState";
+import { dragAtMach, interpCd, interpDsf } from "./dragTables";
+import { atmosphereAtHeight, computeAirDensity, pressureFromAltitude, vaporPressurePa } from "../utils/weather";
+import {
+  downrangeStability, millerStability, reposeLiftGain, spinDecayRatio, spinDriftRate, stabilityBcFactor,
+  stabilityWarning,
+} from "./stability";
+import type { Trajectory, TrajectoryEvent, TrajectorySample } from "./trajectory";
+import { FTLBF_PER_J, MPS_PER_MPH } from "./units";
 
 const g = 9.81;           // m/s²
 const R = 287.05;         // J/(kg·K)
 const RHO0 = 1.225;       // kg/m³ (ICAO sea-level ref)
+const GAMMA = 1.4;        // ratio of specific heats for air
+const OMEGA = 7.2921159e-5; // rad/s (Earth rotation)
+const KG_PER_GR = 6.479891e-5;
+const KG_M2_PER_LB_IN2 = 703.0696; // BC units
 
 /* ---------------- Environment helpers ---------------- */
 
+/** Air density (kg/m³) at station pressure. Humidity counts: moist air is
+ *  lighter, by about 1.6 % at 30 °C and 100 %. Every solve goes through
+ *  here (drag, the BC's zero-day correction, density altitude). Earlier
+ *  versions treated all air as dry, so humid days now show a little less
+ *  drop than before; humidityPct 0 gives the old figure. */
 export function airDensity(env: Environment): number {
   const T = env.temperatureC + 273.15;      // K
   const p = env.pressurehPa * 100;          // Pa
-  return p / (R * T);
+  if (!env.humidityPct) return p / (R * T);
+  // moist air is lighter: split into dry-air and vapor partial pressures
+  return computeAirDensity(p, env.temperatureC, env.humidityPct);
 }
 
+/** Environment for a device with GPS altitude but no barometer.
+ *  Station pressure is estimated from altitude and sea-level pressure (hPa). */
+export function environmentFromAltitude(
+  temperatureC: number,
+  humidityPct: number,
+  altitudeM: number,
+  seaLevelhPa = 1013.25
+): Environment {
+  const pressurehPa = pressureFromAltitude(altitudeM, seaLevelhPa * 100) / 100;
+  return { temperatureC, pressurehPa, humidityPct, altitudeM };
+}
+
+/** Environment for a measured air density (kg/m³), e.g. from a weather
+ *  meter. Temperature still sets the speed of sound; pressure is whatever
+ *  makes air at that temperature and humidity this dense. */
+export function environmentFromDensity(densityKgM3: number, temperatureC = 15, humidityPct = 0): Environment {
+  const T = temperatureC + 273.15;
+  // density is linear in pressure: start from dry air, then add back what
+  // the lighter vapour takes away
+  const dry: Environment = { temperatureC, pressurehPa: (densityKgM3 * R * T) / 100, humidityPct };
+  const pressurehPa = dry.pressurehPa + ((densityKgM3 - airDensity(dry)) * R * T) / 100;
+  return { temperatureC, pressurehPa, humidityPct };
+}
+
+/** Environment for a known density altitude (m). Without a temperature the
+ *  ICAO temperature at that altitude is used. Humidity is kept; the
+ *  pressure is chosen so the moist air still has the DA's density. */
+export function environmentFromDensityAltitude(densityAltitudeM: number, temperatureC?: number, humidityPct = 0): Environment {
+  const rho = RHO0 * Math.pow(1 - densityAltitudeM / 44330.8, 1 / 0.234969);
+  return environmentFromDensity(rho, temperatureC ?? 15 - 0.0065 * densityAltitudeM, humidityPct);
+}
+
+/** Local gravity (m/s²): Somigliana normal gravity for the latitude plus
+ *  the free-air correction for altitude. */
+export function localGravity(latitudeDeg: number, altitudeM = 0): number {
+  const s2 = Math.sin((latitudeDeg * Math.PI) / 180) ** 2;
+  const g0 = (9.7803253359 * (1 + 0.00193185265241 * s2)) / Math.sqrt(1 - 0.00669437999013 * s2);
+  return g0 - 3.086e-6 * altitudeM;
+}
+
+/** Gravity the solver uses: local model when latitude is known, else standard */
+export function gravityFor(env: Environment): number {
+  if (env.latitudeDeg === undefined || !Number.isFinite(env.latitudeDeg)) return g;
+  return localGravity(env.latitudeDeg, env.altitudeM ?? 0);
+}
+
+/** Density altitude (m) — ICAO altitude with the same air density */
+export function densityAltitude(env: Environment): number {
+  return 44330.8 * (1 - Math.pow(airDensity(env) / RHO0, 0.234969));
+}
+
+/** Speed of sound (m/s). Humid air is lighter, so sound is faster: use the
+ *  virtual temperature Tv = T / (1 − x(1 − 0.622)), x = e/p the vapor mole
+ *  fraction, and mix γ with water vapor's 1.33. */
+export function speedOfSound(env: Environment): number {
+  const T = env.temperatureC + 273.15;      // K
+  if (!env.humidityPct) return Math.sqrt(GAMMA * R * T);
+  const x = vaporPressurePa(env.temperatureC, env.humidityPct) / (env.pressurehPa * 100);
+  const Tv = T / (1 - x * (1 - 0.622));
+  const gamma = 1 + 1 / ((1 - x) / (GAMMA - 1) + x / (1.33 - 1));
+  return Math.sqrt(gamma * R * Tv);
+}
+
+/** Muzzle velocity adjusted for powder temperature (taken as ambient).
+ *  V0 is referenced to mvReferenceTempC, or the zero temperature if unset. */
 export function mvCorrected(ammo: AmmoProfile, current: Environment): number {
   if (!ammo.mvTempSensitivity) return ammo.V0;
-  const dT = current.temperatureC - ammo.zeroEnv.temperatureC;
-  return ammo.V0 + ammo.mvTempSensitivity * dT;
+  const refT = ammo.mvReferenceTempC ?? ammo.zeroEnv.temperatureC;
+  const dT = current.temperatureC - refT;
+  return Math.max(1, ammo.V0 + ammo.mvTempSensitivity * dT);
 }
 
 /** Effective BC scaling: drag ∝ ρ, so BC_eff ≈ BC * (ρ_zero / ρ_now) */
@@ -26,78 +110,417 @@ export function bcCorrected(ammo: AmmoProfile, current: Environment): number {
   return ammo.bc * (rhoZero / rhoNow);
 }
 
-/* ---------------- Drag function from tables ---------------- */
-
-function fDrag(model: AmmoProfile["model"], v: number): number {
-  if (model === "G1") return fG1(v);
-  if (model === "G7") return fG7(v);
-  // "noDrag" fallback (nearly zero drag): tiny value avoids div-by-zero
-  return 1e-12;
-}
-
 /* ---------------- Core integrator ----------------
    We integrate in *distance* steps (dx), using:
-     dv/dx = - f(v) / BC_eff
+     dv/dx = - ρ v Cd_ref(M) π / (8 BC_eff)
+   with BC_eff in kg/m² and Cd_ref the standard model's drag coefficient
    and gravity via dt = dx / vx.
    Coordinates:
      - x forward (m), y up (m) relative to *bore line origin*
-     - LOS is a horizontal line at y = scopeHeight (m)
+     - LOS is a line at y = scopeHeight (m); x runs along the LOS, so on an
+       inclined shot gravity is split into along-LOS and cross-LOS parts
 */
 
-type SolveParams = {
+/** Wind that applies from rangeStartM until the next segment starts */
+export type WindSegment = {
+  rangeStartM: number;
+  speed: number;         // m/s
+  angleDeg: number;      // same convention as windAngleDeg
+  verticalMps?: number;  // + updraft, − downdraft
+};
+
+/* Wind direction helpers. The solver's windAngleDeg is where the wind
+   comes from, counter-clockwise from the target: 0 = headwind, 90 = from
+   the left (drift right), 180 = tailwind, 270 = from the right. The
+   crosswind is speed · sin(angle).
+
+   Convention change: earlier versions took speed · cos(angle), so 0 was
+   full value and the default of 90 gave no drift at all. An angle written
+   down under that reading is 90° off: add 90 to it (a full-value 0 becomes
+   90). Nothing the app stores was solved with the old reading. */
+
+/** Clock position the wind blows from (12 = from the target, 3 = from the
+ *  right, 9 = from the left) to the solver's windAngleDeg */
+export function windAngleFromClock(clock: number): number {
+  return (((360 - clock * 30) % 360) + 360) % 360;
+}
+
+/** Inverse of windAngleFromClock (1–12, fractional hours allowed) */
+export function clockFromWindAngle(windAngleDeg: number): number {
+  const c = ((((360 - windAngleDeg) % 360) + 360) % 360) / 30;
+  return c === 0 ? 12 : c;
+}
+
+/** Meteorological wind ("from" bearing, 0 = from N) and the shot azimuth
+ *  (bearing to target) to the solver's windAngleDeg */
+export function windAngleFromBearing(fromBearingDeg: number, shotAzimuthDeg: number): number {
+  return (((shotAzimuthDeg - fromBearingDeg) % 360) + 360) % 360;
+}
+
+/** Turret: click value per detent, in the scope's units */
+export type ScopeSpec = {
+  units: ScopeUnits;
+  clickValue: number;    // e.g. 0.1 MIL or 0.25 MOA
+};
+
+/** When to give up integrating before the requested range is reached.
+ *  Unset fields never trigger. */
+export type StopConditions = {
+  minVelocityMps?: number;
+  minMach?: number;
+  maxTimeS?: number;
+  maxDropBelowLosM?: number;  // default 50 m
+  groundAltitudeM?: number;   // absolute; compared with env.altitudeM + height
+};
+
+/** Why a walk ended: "range" when it reached the requested range, else the
+ *  stop condition that fired, or "observer" when onStep returned false */
+export type Termination = "range" | "minVelocity" | "minMach" | "maxTime" | "maxDrop" | "ground" | "observer";
+
+/** Optional extras shared by the public solve functions */
+export type SolveOptions = {
+  twistRateIn?: number;  // inches per turn, + right-hand; enables spin drift
+  /** "litz" (default): empirical drift vs time of flight. "mpm": modified
+   *  point mass, lift from the yaw of repose integrated along the path */
+  spinDriftModel?: "litz" | "mpm";
+  azimuthDeg?: number;   // bearing to target (0=N, 90=E); with env.latitudeDeg enables Coriolis
+  lookAngleDeg?: number; // LOS inclination (+ uphill); ranges are then slant ranges
+  /** target height above (+) / below (−) the muzzle, m. Ranges are then
+   *  horizontal distances and the look angle follows; overrides lookAngleDeg */
+  targetHeightM?: number;
+  windSegments?: WindSegment[]; // overrides windSpeed/windAngleDeg when given
+  integrator?: "fixed" | "rkf45"; // fixed dx steps (default) or adaptive RKF45
+  smoothDrag?: boolean;  // monotone-cubic drag lookup instead of linear (no kinks at table points)
+  toleranceM?: number;   // RKF45 position error per step (m), default 1e-4
+  scope?: ScopeSpec;     // adds click-rounded corrections to each row
+  stop?: StopConditions;
+  cantDeg?: number;      // rifle cant, + clockwise (top of scope to the right)
+  windVerticalMps?: number; // + updraft, − downdraft (ignored with windSegments)
+  /** bore angle from solveZeroAngle for this ammo/zero; skips the zero
+   *  solve when the same rifle/load is solved repeatedly */
+  zeroAngleRad?: number;
+  /** called after every accepted step; return false to stop there (the
+   *  row is then reported at the point reached) */
+  onStep?: StepObserver;
+};
+
+/** State after each accepted integrator step */
+export type StepState = {
+  x: number;             // m along LOS
+  yAboveLos: number;     // m
+  z: number;             // m right of LOS (wind + spin + Coriolis)
+  v: number;             // m/s
+  mach: number;
+  t: number;             // s
+  vx: number;            // m/s velocity components, same axes as x/y/z
+  vy: number;
+  vz: number;
+};
+
+export type StepObserver = (s: StepState) => boolean | void;
+
+/** Run the caller's observer alongside one of ours; either may stop the walk */
+function observeAlso(user: StepObserver | undefined, own: StepObserver): StepObserver {
+  if (!user) return own;
+  return (s) => {
+    const a = own(s);
+    const b = user(s);
+    return a === false || b === false ? false : undefined;
+  };
+}
+
+type SolveParams = SolveOptions & {
   ammo: AmmoProfile;
   env: Environment;
   rangeM: number;
   windSpeed?: number;    // m/s (at 90° gives max drift)
-  windAngleDeg?: number; // 0=headwind, 90=full value from left
+  windAngleDeg?: number; // 0=headwind, 90=full value from left (crosswind = speed·sin)
   dx?: number;           // step size (m), default 1.0
   launchAngleRad: number;// bore angle above LOS (radians)
+  /** drift the zeroing-day wind gave at the zero distance (m), from
+   *  zeroDayDrift(); solved once per public call, 0 when not passed */
+  zeroDayDriftM?: number;
 };
 
+/** Run a solve to completion; onStep (if any) sees every step and may
+ *  stop the integration early */
 function integrateToRange(p: SolveParams) {
-  const { ammo, env, rangeM, windSpeed = 0, windAngleDeg = 90, dx = 1, launchAngleRad } = p;
+  const ctl = { stop: false };
+  const steps = integrateSteps(p, !!p.onStep, ctl);
+  for (;;) {
+    const r = steps.next();
+    if (r.done) return r.value;
+    ctl.stop = p.onStep!(r.value) === false;
+  }
+}
+
+/** The integrator proper. Yields each step's state when `emit` is set (so
+ *  plain solves don't pay for building them) and returns the row. Setting
+ *  ctl.stop while suspended ends the walk at the step just yielded. */
+function* integrateSteps(p: SolveParams, emit: boolean, ctl = { stop: false }) {
+  const {
+    ammo, env, rangeM, windSpeed = 0, windAngleDeg = 90, dx = 1, launchAngleRad,
+    lookAngleDeg = 0, twistRateIn, azimuthDeg, windSegments,
+    integrator = "fixed", toleranceM = 1e-4, scope, stop = {}, cantDeg = 0,
+    windVerticalMps = 0, spinDriftModel = "litz", smoothDrag = false,
+  } = p;
 
-  const BCeff = bcCorrected(ammo, env);
   const V0    = mvCorrected(ammo, env);
   const yLOS  = (ammo.scopeHeightMm ?? 0) / 1000; // LOS height above bore at muzzle (m)
 
-  // Initial state at muzzle (x=0, y=yLOS because we measure relative to LOS)
+  // gyroscopic stability (Miller), needs twist + bullet geometry; a
+  // marginally stable bullet loses BC and drifts per Litz
+  const sg = twistRateIn ? millerStability(ammo, twistRateIn, env, V0) : null;
+  const bcFactor = sg !== null ? stabilityBcFactor(sg) : 1;
+
+  const BCeff = bcCorrected(ammo, env) * bcFactor;
+
+  // Drag as a rate (1/s): dv/dx = -rate. Standard models scale the G-family
+  // Cd by BC: rate = ρ Cd v π / (8 BC). BCeff is already scaled from the zero
+  // day's air to today's, so it is paired with the zero day's density. A
+  // measured Cd(Mach) curve uses the bullet's own mass and frontal area:
+  // rate = ρ A Cd v / (2 m).
+  const rho0 = airDensity(env);
+  const rhoBc = airDensity(ammo.zeroEnv);
+  const custom = ammo.cdTable?.length && ammo.bulletDiameterMm ? ammo.cdTable : null;
+  const areaM2 = Math.PI * ((ammo.bulletDiameterMm ?? 0) / 2000) ** 2;
+  const massKg = Math.max(1e-6, (ammo.bulletWeightGr || 0) * KG_PER_GR);
+  // trued drag scale factors, if any, multiply either model at the local Mach
+  const dsf = ammo.dsfTable?.length ? ammo.dsfTable : null;
+  const dragRate = (speed: number, a: number, rhoRatio: number) => {
+    const k = dsf ? interpDsf(dsf, speed / a) : 1;
+    if (custom) return (k * rho0 * rhoRatio * areaM2 * interpCd(custom, speed / a, smoothDrag) * speed) / (2 * massKg * bcFactor);
+    return (k * rhoBc * rhoRatio * dragAtMach(ammo.model, speed / a, smoothDrag) * speed * Math.PI)
+      / (8 * KG_M2_PER_LB_IN2 * Math.max(1e-12, BCeff));
+  };
+
+  // Cant rotates the bore about the LOS: the bore (sight height below the
+  // scope) swings sideways, and the elevation set on the rifle is split into
+  // a vertical part and a sideways part.
+  const cantRad = (cantDeg * Math.PI) / 180;
+
+  // Initial state at muzzle: bore sits sightHeight below the LOS (y = yLOS)
   let x = 0;
-  let y = yLOS;
+  let y = yLOS - yLOS * Math.cos(cantRad);
   let v = Math.max(0.1, V0);
-  let th = launchAngleRad; // current velocity angle relative to horizontal LOS
+  let th = launchAngleRad * Math.cos(cantRad); // current velocity angle relative to horizontal LOS
+
+  // sideways motion from cant: starts off-axis, drifts with the bore's yaw,
+  // and (relative to still air) is slowed by drag
+  let zCant = -yLOS * Math.sin(cantRad);
+  let vzCant = v * Math.sin(launchAngleRad * Math.sin(cantRad));
 
   // components (for dt)
   let vx = v * Math.cos(th);
   let vy = v * Math.sin(th);
 
   let t = 0;
-  let drift = 0;
 
-  // precompute wind component (crosswind only)
-  const windCross = windSpeed * Math.cos((windAngleDeg * Math.PI) / 180); // + from left to right
+  // gravity split relative to the (possibly inclined) LOS
+  const inclineRad = (lookAngleDeg * Math.PI) / 180;
+  const gLocal = gravityFor(env);
+  const gPerp  = gLocal * Math.cos(inclineRad);
+  const gAlong = gLocal * Math.sin(inclineRad);
+
+  // air along the path: density and speed of sound follow the bullet's height
+  // above the muzzle (lapse-rate model), drag is looked up at the local Mach
+  const a0 = speedOfSound(env);
+  let aLocal = a0;
+  let rhoRatio = 1; // local / muzzle density
+  const airAt = (xNow: number, yNow: number) => {
+    // height above the muzzle in world frame -> local air
+    const dh = xNow * Math.sin(inclineRad) + (yNow - yLOS) * Math.cos(inclineRad);
+    const air = atmosphereAtHeight(env.temperatureC, env.pressurehPa * 100, dh);
+    const T0 = env.temperatureC + 273.15;
+    const T = air.temperatureC + 273.15;
+    return {
+      rhoRatio: (air.pressurePa / (env.pressurehPa * 100)) * (T0 / T),
+      a: a0 * Math.sqrt(T / T0),
+    };
+  };
+
+  // Spin decays with the air swept (∫ρ ds). Litz's drift is integrated with
+  // Sg scaled by (p/p0)², so it reflects the spin actually left downrange.
+  // The MPM model instead accelerates the bullet sideways with the lift of
+  // its yaw of repose, which scales with p g⊥ / V.
+  let rhoPath = 0; // ∫ρ ds (kg/m²)
+  let zSpin = 0, vzSpin = 0;
+  const reposeGain = spinDriftModel === "mpm" && sg !== null && twistRateIn
+    ? reposeLiftGain(ammo, sg, rho0, V0, twistRateIn)
+    : 0;
+  const spinRateAt = (tNow: number, rhoPathNow: number) =>
+    sg !== null && twistRateIn && spinDriftModel !== "mpm"
+      ? spinDriftRate(sg * spinDecayRatio(ammo, rhoPathNow) ** 2, tNow, twistRateIn)
+      : 0;
+  const spinAccelAt = (speed: number, thNow: number, rhoPathNow: number) =>
+    reposeGain ? (reposeGain * spinDecayRatio(ammo, rhoPathNow) * gLocal * Math.cos(inclineRad + thNow)) / speed : 0;
+
+  // termination checks besides reaching rangeM
+  const {
+    minVelocityMps = 0, minMach = 0, maxTimeS = Infinity,
+    maxDropBelowLosM = 50, groundAltitudeM = -Infinity,
+  } = stop;
+  let terminatedBy: Termination = "range";
+  const shouldStop = (xNow: number, yNow: number, vNow: number, aNow: number, tNow: number) => {
+    const heightM = xNow * Math.sin(inclineRad) + (yNow - yLOS) * Math.cos(inclineRad);
+    const why: Termination | null =
+      vNow < minVelocityMps ? "minVelocity" :
+      vNow / aNow < minMach ? "minMach" :
+      tNow > maxTimeS ? "maxTime" :
+      yLOS - yNow > maxDropBelowLosM ? "maxDrop" :
+      (env.altitudeM ?? 0) + heightM < groundAltitudeM ? "ground" :
+      null;
+    if (why) terminatedBy = why;
+    return why !== null;
+  };
+
+  // Earth rotation in shot axes (forward, up, right); zero unless both
+  // latitude and azimuth are known. Coriolis acceleration is -2 Ω × v.
+  const useCoriolis = env.latitudeDeg !== undefined && azimuthDeg !== undefined;
+  const phi = ((env.latitudeDeg ?? 0) * Math.PI) / 180;
+  const psi = ((azimuthDeg ?? 0) * Math.PI) / 180;
+  const OmF = useCoriolis ? OMEGA * Math.cos(phi) * Math.cos(psi) : 0;
+  const OmU = useCoriolis ? OMEGA * Math.sin(phi) : 0;
+  const OmR = useCoriolis ? -OMEGA * Math.cos(phi) * Math.sin(psi) : 0;
+  let vz = 0, z = 0;          // lateral (Coriolis) velocity/position, + right
+  let vyCor = 0, yCor = 0;    // vertical (Eötvös) part, already included in y
+
+  // crosswind component (+ from left to right), per segment if a profile is given
+  const crossOf = (speed: number, angleDeg: number) => speed * Math.sin((angleDeg * Math.PI) / 180);
+  const segments = (windSegments ?? [])
+    .slice()
+    .sort((a, b) => a.rangeStartM - b.rangeStartM);
+  const windCrossAt = (xNow: number) => {
+    if (!segments.length) return crossOf(windSpeed, windAngleDeg);
+    let w = 0;
+    for (const seg of segments) if (seg.rangeStartM <= xNow) w = crossOf(seg.speed, seg.angleDeg);
+    return w;
+  };
+  // vertical wind, projected across the (possibly inclined) LOS
+  const windUpAt = (xNow: number) => {
+    let w = windVerticalMps;
+    if (segments.length) {
+      w = 0;
+      for (const seg of segments) if (seg.rangeStartM <= xNow) w = seg.verticalMps ?? 0;
+    }
+    return w * Math.cos(inclineRad);
+  };
+
+  // Lateral wind response: the bullet's sideways velocity relaxes toward the
+  // wind at the same fractional rate drag slows it down (gives the lag rule
+  // for a constant wind). A unit-wind channel gives drift per 1 m/s.
+  let vzW = 0, zW = 0;
+  let vzU = 0, zU = 0;
 
-  while (x < rangeM && y > -50) {
+  if (integrator === "rkf45") {
+    // Adaptive Runge–Kutta–Fehlberg 4(5) in time. State:
+    // [x, y, vx, vy, zW, vzW, zU, vzU, zC, vzC, yC, vyC, zK, vzK, ∫ρds, zS, t, vzS]
+    // (wind, unit-wind and Coriolis lateral channels, Eötvös vertical channel,
+    //  cant lateral channel, spin decay and spin drift, time, MPM drift velocity)
+    const deriv = (s: number[]): number[] => {
+      const sp = Math.max(0.1, Math.hypot(s[2], s[3]));
+      const air = airAt(s[0], s[1]);
+      // dv/dx = -rate  <=>  a = -rate·v  (rate is 1/s)
+      const kv = dragRate(sp, air.a, air.rhoRatio);
+      const aCorUp = -2 * OmR * s[2];
+      return [
+        s[2], s[3],
+        -kv * s[2] - gAlong,
+        -kv * (s[3] - windUpAt(s[0])) - gPerp + aCorUp,
+        s[5], kv * (windCrossAt(s[0]) - s[5]),
+        s[7], kv * (1 - s[7]),
+        s[9], 2 * (OmU * s[2] - OmF * s[3]),
+        s[11], aCorUp,
+        s[13], -kv * s[13],
+        rho0 * air.rhoRatio * sp, spinRateAt(s[16], s[14]) + s[17], 1,
+        spinAccelAt(sp, Math.atan2(s[3], s[2]), s[14]) - kv * s[17],
+      ];
+    };
+    const add = (s: number[], h: number, ks: number[][], cs: number[]) =>
+      s.map((si, i) => si + h * cs.reduce((acc, c, j) => acc + c * ks[j][i], 0));
+    const rkf = (s: number[], h: number) => {
+      const k1 = deriv(s);
+      const k2 = deriv(add(s, h, [k1], [1 / 4]));
+      const k3 = deriv(add(s, h, [k1, k2], [3 / 32, 9 / 32]));
+      const k4 = deriv(add(s, h, [k1, k2, k3], [1932 / 2197, -7200 / 2197, 7296 / 2197]));
+      const k5 = deriv(add(s, h, [k1, k2, k3, k4], [439 / 216, -8, 3680 / 513, -845 / 4104]));
+      const k6 = deriv(add(s, h, [k1, k2, k3, k4, k5], [-8 / 27, 2, -3544 / 2565, 1859 / 4104, -11 / 40]));
+      const ks = [k1, k2, k3, k4, k5, k6];
+      const s4 = add(s, h, ks, [25 / 216, 0, 1408 / 2565, 2197 / 4104, -1 / 5, 0]);
+      const s5 = add(s, h, ks, [16 / 135, 0, 6656 / 12825, 28561 / 56430, -9 / 50, 2 / 55]);
+      const err = Math.max(Math.abs(s5[0] - s4[0]), Math.abs(s5[1] - s4[1]), Math.abs(s5[4] - s4[4]));
+      return { next: s5, err };
+    };
+
+    let st = [x, y, vx, vy, 0, 0, 0, 0, 0, 0, 0, 0, zCant, vzCant, 0, 0, 0, 0];
+    let h = 1e-3;
+    while (st[0] < rangeM - 1e-6 && !shouldStop(st[0], st[1], Math.hypot(st[2], st[3]), aLocal, t)) {
+      const { next, err } = rkf(st, h);
+      if (err > toleranceM && h > 1e-7) {
+        h *= Math.max(0.2, 0.9 * Math.pow(toleranceM / err, 0.25));
+        continue;
+      }
+      if (next[0] > rangeM) {
+        // shorten the step to land on the requested range
+        h *= (rangeM - st[0]) / (next[0] - st[0]);
+        continue;
+      }
+      st = next;
+      t += h;
+      h *= Math.min(5, 0.9 * Math.pow(toleranceM / Math.max(err, 1e-16), 0.2));
+      const air = airAt(st[0], st[1]);
+      aLocal = air.a;
+      const sp = Math.hypot(st[2], st[3]);
+      if (emit) yield {
+        x: st[0], yAboveLos: st[1] - yLOS, z: st[4] + st[8] + st[12] + st[15], v: sp, mach: sp / aLocal, t,
+        vx: st[2], vy: st[3], vz: st[5] + st[9] + st[13] + spinRateAt(st[16], st[14]) + st[17],
+      };
+      if (ctl.stop) { terminatedBy = "observer"; break; }
+    }
+    [x, y, vx, vy, zW, vzW, zU, vzU, z, vz, yCor, vyCor, zCant, vzCant, rhoPath, zSpin, , vzSpin] = st;
+    v = Math.hypot(vx, vy);
+  }
+
+  while (integrator === "fixed" && x < rangeM && !shouldStop(x, y, v, aLocal, t)) {
     // distance step
     const step = Math.min(dx, rangeM - x);
 
     // drag retardation in speed per distance
-    const f = fDrag(ammo.model, v);
-    const dv_drag = -(f / Math.max(1e-12, BCeff)) * step; // dv from drag along velocity vector
+    const dv_drag = -dragRate(v, aLocal, rhoRatio) * step; // dv from drag along velocity vector
 
     // time step from horizontal component
     vx = Math.max(0.1, v * Math.cos(th));
     const dt = step / vx;
+    const kDrag = -dv_drag / (v * dt); // drag as a fractional rate (1/s)
 
-    // apply gravity to vertical component over dt
-    vy = v * Math.sin(th) - g * dt;
+    // spin drift with the spin left at this point (midpoint in time)
+    zSpin += (spinRateAt(t + dt / 2, rhoPath) + vzSpin) * dt;
+    vzSpin += (spinAccelAt(v, th, rhoPath) - kDrag * vzSpin) * dt;
+    rhoPath += rho0 * rhoRatio * v * dt;
 
-    // new speed magnitude (drag reduces |v|)
-    const v_afterDrag = Math.max(0.1, v + dv_drag);
+    // apply gravity (and Eötvös, if enabled) to vertical component over dt;
+    // drag acts on air-relative velocity, so vertical wind pushes by kDrag·w
+    const aCorUp = -2 * OmR * vx;
+    vy = v * Math.sin(th) - gPerp * dt + aCorUp * dt + kDrag * windUpAt(x) * dt;
+    vyCor += aCorUp * dt;
+
+    // horizontal Coriolis
+    vz += 2 * (OmU * vx - OmF * vy) * dt;
+
+    // new speed magnitude (drag reduces |v|, uphill gravity slows it further)
+    const v_afterDrag = Math.max(0.1, v + dv_drag - gAlong * dt);
 
     // recompute angle from components after gravity (direction change)
     const th_new = Math.atan2(vy, vx);
 
+    // wind drift (uses the speed at the start of the step)
+    vzW += kDrag * (windCrossAt(x) - vzW) * dt;
+    vzU += kDrag * (1 - vzU) * dt;
+    vzCant -= kDrag * vzCant * dt;
+    zCant += vzCant * dt;
+    zW += vzW * dt;
+    zU += vzU * dt;
+
     // project new magnitude along new direction
     v = v_afterDrag;
     th = th_new;
@@ -105,36 +528,141 @@ function integrateToRange(p: SolveParams) {
     // advance position
     x += step;
     y += vy * dt; // vertical position relative to LOS
+    z += vz * dt;
+    yCor += vyCor * dt;
 
-    // wind drift: simple "carried by wind" approx (can be refined later)
-    drift += windCross * dt;
+    const air = airAt(x, y);
+    rhoRatio = air.rhoRatio;
+    aLocal = air.a;
 
     t += dt;
+    if (emit) yield {
+      x, yAboveLos: y - yLOS, z: zW + z + zCant + zSpin, v, mach: v / aLocal, t,
+      vx: v * Math.cos(th), vy: v * Math.sin(th), vz: vzW + vz + vzCant + spinRateAt(t, rhoPath) + vzSpin,
+    };
+    if (ctl.stop) { terminatedBy = "observer"; break; }
   }
 
+  // a walk cut short reports (and takes its holds at) the point it reached
+  const reachedRangeM = x;
+  if (reachedRangeM >= rangeM - 1e-6) terminatedBy = "range";
+  const atM = Math.max(1e-6, reachedRangeM);
+
   const drop = yLOS - y; // vertical drop below LOS at range
   const impactVel = v;
+  const mach = v / aLocal;
+
+  const drift = zW;
+  const driftPerMps = zU;                      // m drift per 1 m/s full-value wind
+  const driftPerMph = zU * MPS_PER_MPH;        // m drift per 1 mph full-value wind
+
+  const spinDriftM = zSpin;
+  const spinRatio = spinDecayRatio(ammo, rhoPath); // spin left / muzzle spin
+  const sgDownrange = sg !== null && twistRateIn ? downrangeStability(ammo, twistRateIn, env, V0, v, spinRatio) : null;
+
+  // remaining energy / momentum
+  const energyJ = 0.5 * massKg * v * v;
+  const energyFtLbf = energyJ * FTLBF_PER_J;
+  const momentumNs = massKg * v;
 
   // holds
-  const holdMil = (drop / rangeM) * 1000;
+  const holdMil = (drop / atM) * 1000;
   const holdMoa = holdMil * 3.43774677;
+  // a zero that groups off to the side keeps that angle at every range; a
+  // zero set in wind has the zeroing-day drift dialed out, which leaves the
+  // rifle pointing that much upwind when the wind drops
+  const Z = Math.max(1, ammo.zeroDistanceM || 100);
+  const zeroRightMil = (((ammo.zeroOffsetRightCm ?? 0) / 100 - (p.zeroDayDriftM ?? 0)) / Z) * 1000;
+  const lateralHoldMil = ((drift + spinDriftM + z + zCant) / atM) * 1000 + zeroRightMil; // wind + spin + Coriolis + cant + zero offset
+  const clicks = scope ? turretClicks(holdMil, lateralHoldMil, scope) : undefined;
+
+  return {
+    tof: t, impactVel, mach, energyJ, energyFtLbf, momentumNs, dropM: drop, driftM: drift, driftPerMps, driftPerMph,
+    spinDriftM, coriolisDriftM: z, eotvosM: yCor, cantDriftM: zCant, lateralHoldMil, holdMil, holdMoa, clicks,
+    sg, sgDownrange, spinRatio, reachedRangeM, terminatedBy,
+    // past Mach 1 the bullet may lose stability; treat holds as less certain
+    warning: (sg !== null ? stabilityWarning(sg) : undefined) ?? (mach < 1 ? "subsonic: stability not assured" : undefined),
+  };
+}
+
+/** Round holds to whole turret clicks.
+ *  elevation: + = dial UP; windage: + = dial LEFT (drift was to the right).
+ *  Residuals are what is left after dialing, in the scope's units. */
+export function turretClicks(holdMil: number, lateralHoldMil: number, scope: ScopeSpec) {
+  const toUnits = (mil: number) => (scope.units === "MIL" ? mil : mil * 3.43774677);
+  const click = Math.max(1e-9, scope.clickValue);
+  const elev = toUnits(holdMil);
+  const wind = toUnits(lateralHoldMil);
+  const elevationClicks = Math.round(elev / click);
+  const windageClicks = Math.round(wind / click);
+  return {
+    units: scope.units,
+    elevationClicks,
+    windageClicks,
+    elevationResidual: elev - elevationClicks * click,
+    windageResidual: wind - windageClicks * click,
+  };
+}
+
+/** Wind drift at the zero distance on zeroing day (m), which the windage
+ *  zero absorbed; 0 if the load was zeroed in still air */
+function zeroDayDrift(ammo: AmmoProfile): number {
+  if (!ammo.zeroWindSpeed) return 0;
+  return integrateToRange({
+    ammo,
+    env: ammo.zeroEnv,
+    rangeM: Math.max(1, ammo.zeroDistanceM || 100),
+    windSpeed: ammo.zeroWindSpeed,
+    windAngleDeg: ammo.zeroWindAngleDeg ?? 90,
+    launchAngleRad: 0,
+  }).driftM;
+}
 
-  return { tof: t, impactVel, dropM: drop, driftM: drift, holdMil, holdMoa };
+/** One DOPE row. With cant, the shooter is assumed to dial the level-rifle
+ *  hold for this range and then fire canted: the returned holdMil and
+ *  lateralHoldMil include the correction for the cant-induced miss, which
+ *  is also reported as cantVerticalErrorM / cantDriftM. */
+function solveRow(p: SolveParams) {
+  if (p.targetHeightM !== undefined) {
+    // horizontal distance + height difference -> slant range along the LOS
+    const h = p.targetHeightM;
+    p = { ...p, rangeM: Math.hypot(p.rangeM, h), lookAngleDeg: (Math.atan2(h, p.rangeM) * 180) / Math.PI };
+  }
+  const zeroAngleRad = p.launchAngleRad;
+  if (p.zeroDayDriftM === undefined) p = { ...p, zeroDayDriftM: zeroDayDrift(p.ammo) };
+  if (!p.cantDeg) return { ...integrateToRange(p), cantVerticalErrorM: 0, zeroAngleRad };
+  const level = integrateToRange({ ...p, cantDeg: 0, onStep: undefined }); // observers see the shot as fired
+  const canted = integrateToRange({ ...p, launchAngleRad: p.launchAngleRad + level.holdMil / 1000 });
+  const holdMil = level.holdMil + canted.holdMil;
+  const clicks = p.scope ? turretClicks(holdMil, canted.lateralHoldMil, p.scope) : undefined;
+  return {
+    ...canted,
+    dropM: level.dropM,
+    holdMil,
+    holdMoa: holdMil * 3.43774677,
+    clicks,
+    cantVerticalErrorM: canted.dropM,
+    zeroAngleRad,
+    reachedRangeM: Math.min(level.reachedRangeM, canted.reachedRangeM),
+    terminatedBy: level.terminatedBy === "range" ? canted.terminatedBy : level.terminatedBy,
+  };
 }
 
 /* ---------------- Find launch angle for a requested zero ----------------
    We choose the bore angle so the trajectory crosses LOS at zeroDistanceM.
-   Binary search on angle to make drop at zero ≈ 0.
+   Binary search on angle to make drop at zero ≈ 0 (or ≈ −zeroOffsetUp when
+   the zero is known to group high/low, which then carries to every range).
 */
 export function solveZeroAngle(ammo: AmmoProfile, env: Environment): number {
   const Z = Math.max(1, ammo.zeroDistanceM || 100);
+  const target = -(ammo.zeroOffsetUpCm ?? 0) / 100;
   // search 0..10 mrad (~0..0.57°), then expand if needed
   let lo = 0;
   let hi = 0.010; // radians
   let best = 0;
 
   const tryWith = (ang: number) =>
-    integrateToRange({ ammo, env, rangeM: Z, launchAngleRad: ang }).dropM;
+    integrateToRange({ ammo, env, rangeM: Z, launchAngleRad: ang }).dropM - target;
 
   // expand upper bound until we bracket sign change or reach 50 mrad
   let dLo = tryWith(lo); // drop at zero for lo
@@ -172,10 +700,12 @@ export function solveTrajectory(
   rangeM: number,
   windSpeed = 0,
   windAngleDeg = 90,
-  dx = 1.0
+  dx = 1.0,
+  opts: SolveOptions = {}
 ) {
-  const angle = solveZeroAngle(ammo, currentEnv);
-  return integrateToRange({
+  const angle = opts.zeroAngleRad ?? solveZeroAngle(ammo, currentEnv);
+  return solveRow({
+    ...opts,
     ammo,
     env: currentEnv,
     rangeM,
@@ -186,17 +716,38 @@ export function solveTrajectory(
   });
 }
 
+/** Solve a shot from what the rangefinder reports: slant range along the
+ *  LOS and inclination (deg, + uphill). The zero is still solved level, and
+ *  holds are returned along the inclined LOS, so no rifleman's-rule
+ *  pre-conversion is needed (or allowed) by the caller. */
+export function solveSlantRange(
+  ammo: AmmoProfile,
+  currentEnv: Environment,
+  slantRangeM: number,
+  inclineDeg: number,
+  windSpeed = 0,
+  windAngleDeg = 90,
+  dx = 1.0,
+  opts: SolveOptions = {}
+) {
+  const res = solveTrajectory(ammo, currentEnv, slantRangeM, windSpeed, windAngleDeg, dx, { ...opts, lookAngleDeg: inclineDeg });
+  return { ...res, horizontalRangeM: slantRangeM * Math.cos((inclineDeg * Math.PI) / 180) };
+}
+
 /** Build a DOPE table over many ranges */
 export function buildDopeTable(
   ammo: AmmoProfile,
   currentEnv: Environment,
   rangesM: number[],
   windSpeed = 0,
-  windAngleDeg = 90
+  windAngleDeg = 90,
+  opts: SolveOptions = {}
 ) {
-  const angle = solveZeroAngle(ammo, currentEnv);
+  const angle = opts.zeroAngleRad ?? solveZeroAngle(ammo, currentEnv);
+  const zeroDayDriftM = zeroDayDrift(ammo);
   return rangesM.map((R) =>
-    integrateToRange({
+    solveRow({
+      ...opts,
       ammo,
       env: currentEnv,
       rangeM: R,
@@ -204,6 +755,552 @@ export function buildDopeTable(
       windAngleDeg,
       dx: R < 200 ? 0.5 : 1.0, // smaller steps at close range
       launchAngleRad: angle,
+      zeroDayDriftM,
     })
   );
 }
+
+/* ---------------- Checked API ----------------
+   buildDopeTable never throws, but will happily return garbage for bad
+   inputs. Callers that need to report *why* a solve failed (import, UI
+   validation, workers) should use the try* variants.
+*/
+
+export type SolveError =
+  | { kind: "InvalidBc"; message: string }
+  | { kind: "InvalidVelocity"; message: string }
+  | { kind: "InvalidStep"; message: string }
+  | { kind: "InvalidRange"; message: string }
+  | { kind: "RangesNotIncreasing"; message: string }
+  | { kind: "ZeroNotConverged"; message: string }
+  | { kind: "InvalidDragTable"; message: string }
+  | { kind: "InvalidEnvironment"; message: string }
+  | { kind: "InvalidRequest"; message: string }
+  | { kind: "RangeNotReached"; message: string };
+
+export type SolveResult<T> = { ok: true; value: T } | { ok: false; error: SolveError };
+
+function validateEnvironment(env: Environment): SolveError | null {
+  if (!(env.pressurehPa > 0)) {
+    return { kind: "InvalidEnvironment", message: `Pressure must be positive (got ${env.pressurehPa} hPa)` };
+  }
+  if (!(env.temperatureC > -273.15)) {
+    return { kind: "InvalidEnvironment", message: `Temperature must be above absolute zero (got ${env.temperatureC} °C)` };
+  }
+  if (!(env.humidityPct >= 0 && env.humidityPct <= 100)) {
+    return { kind: "InvalidEnvironment", message: `Humidity must be 0–100 % (got ${env.humidityPct})` };
+  }
+  return null;
+}
+
+/** Check a solve's inputs: the load, its zero-day environment, the
+ *  environment solved in (when given), the step and the ranges */
+function validateInputs(ammo: AmmoProfile, rangesM: number[], dx = 1, env?: Environment): SolveError | null {
+  const badEnv = (env && validateEnvironment(env)) ?? validateEnvironment(ammo.zeroEnv);
+  if (badEnv) return badEnv;
+  if (ammo.cdTable) {
+    if (!(ammo.bulletDiameterMm! > 0)) {
+      return { kind: "InvalidDragTable", message: "A Cd(Mach) table needs the bullet diameter" };
+    }
+    if (ammo.cdTable.length < 2 || ammo.cdTable.some((p, i) => i > 0 && p.mach <= ammo.cdTable![i - 1].mach)) {
+      return { kind: "InvalidDragTable", message: "Cd(Mach) table needs at least two points in increasing Mach" };
+    }
+  } else if (ammo.model !== "noDrag" && !(ammo.bc > 0)) {
+    return { kind: "InvalidBc", message: `BC must be positive (got ${ammo.bc})` };
+  }
+  if (!(ammo.V0 > 0)) {
+    return { kind: "InvalidVelocity", message: `Muzzle velocity must be positive (got ${ammo.V0})` };
+  }
+  if (!(dx > 0)) {
+    return { kind: "InvalidStep", message: `Step size must be positive (got ${dx})` };
+  }
+  for (let i = 0; i < rangesM.length; i++) {
+    if (!Number.isFinite(rangesM[i]) || rangesM[i] <= 0) {
+      return { kind: "InvalidRange", message: `Range #${i + 1} must be a positive number` };
+    }
+    if (i > 0 && rangesM[i] <= rangesM[i - 1]) {
+      return { kind: "RangesNotIncreasing", message: `Ranges must be increasing (${rangesM[i - 1]} → ${rangesM[i]})` };
+    }
+  }
+  return null;
+}
+
+/** Checked zero solve: fails if the bisection can't put the path on the LOS
+ *  at the zero distance (e.g. zero beyond the bullet's reach). */
+export function trySolveZeroAngle(ammo: AmmoProfile, currentEnv: Environment): SolveResult<number> {
+  const bad = validateInputs(ammo, [Math.max(1, ammo.zeroDistanceM || 100)], 1, currentEnv);
+  if (bad) return { ok: false, error: bad };
+  const angle = solveZeroAngle(ammo, currentEnv);
+  const Z = Math.max(1, ammo.zeroDistanceM || 100);
+  const miss = integrateToRange({ ammo, env: currentEnv, rangeM: Z, launchAngleRad: angle }).dropM
+    + (ammo.zeroOffsetUpCm ?? 0) / 100;
+  if (!Number.isFinite(miss) || Math.abs(miss) > 0.01) {
+    return { ok: false, error: { kind: "ZeroNotConverged", message: `Zero at ${Z} m missed the LOS by ${(miss * 100).toFixed(1)} cm` } };
+  }
+  return { ok: true, value: angle };
+}
+
+/** Checked version of buildDopeTable */
+export function tryBuildDopeTable(
+  ammo: AmmoProfile,
+  currentEnv: Environment,
+  rangesM: number[],
+  windSpeed = 0,
+  windAngleDeg = 90,
+  opts: SolveOptions = {}
+): SolveResult<SolveRow[]> {
+  const bad = validateInputs(ammo, rangesM, 1, currentEnv);
+  if (bad) return { ok: false, error: bad };
+  if (opts.zeroAngleRad === undefined) {
+    const zero = trySolveZeroAngle(ammo, currentEnv);
+    if (!zero.ok) return zero;
+    opts = { ...opts, zeroAngleRad: zero.value };
+  }
+  const rows = buildDopeTable(ammo, currentEnv, rangesM, windSpeed, windAngleDeg, opts);
+  const short = rows.find((row) => row.terminatedBy !== "range");
+  if (short) return { ok: false, error: rangeNotReached(short, rangesM[rows.indexOf(short)]) };
+  return { ok: true, value: rows };
+}
+
+function rangeNotReached(row: SolveRow, rangeM: number): SolveError {
+  return {
+    kind: "RangeNotReached",
+    message: `Stopped at ${row.reachedRangeM.toFixed(1)} m of ${rangeM} m (${row.terminatedBy})`,
+  };
+}
+
+/** Checked version of solveTrajectory: fails instead of returning a row
+ *  that a stop condition ended short of the range */
+export function trySolveTrajectory(
+  ammo: AmmoProfile,
+  currentEnv: Environment,
+  rangeM: number,
+  windSpeed = 0,
+  windAngleDeg = 90,
+  dx = 1.0,
+  opts: SolveOptions = {}
+): SolveResult<SolveRow> {
+  const bad = validateInputs(ammo, [rangeM], dx, currentEnv);
+  if (bad) return { ok: false, error: bad };
+  if (opts.zeroAngleRad === undefined) {
+    const zero = trySolveZeroAngle(ammo, currentEnv);
+    if (!zero.ok) return zero;
+    opts = { ...opts, zeroAngleRad: zero.value };
+  }
+  const row = solveTrajectory(ammo, currentEnv, rangeM, windSpeed, windAngleDeg, dx, opts);
+  if (row.terminatedBy !== "range") return { ok: false, error: rangeNotReached(row, rangeM) };
+  return { ok: true, value: row };
+}
+
+/** ICAO standard atmosphere at sea level */
+export const STANDARD_ENV: Environment = { temperatureC: 15, pressurehPa: 1013.25, humidityPct: 0 };
+
+/** Everything one table solve needs, fully defaulted */
+export type SolveInputs = {
+  ammo: AmmoProfile;
+  env: Environment;
+  rangesM: number[];
+  windSpeed: number;
+  windAngleDeg: number;
+  opts: SolveOptions;
+};
+
+/** What createSolveInputs needs at minimum; everything else is defaulted */
+export type SolveInputsDraft = {
+  ammo: Pick<AmmoProfile, "bulletWeightGr" | "bc" | "V0"> & Partial<AmmoProfile>;
+  env?: Environment;            // default: STANDARD_ENV
+  rangesM?: number[];           // default: every stepM out to maxRangeM
+  maxRangeM?: number;           // default 1000
+  stepM?: number;               // default 100
+  windSpeed?: number;           // default 0
+  windAngleDeg?: number;        // default 90 (full value)
+  opts?: SolveOptions;
+};
+
+/** Fill in defaults (G7, 100 m zero, 40 mm sight height, standard
+ *  atmosphere, 100 m steps to 1000 m) and validate, so application code
+ *  doesn't have to assemble a full AmmoProfile by hand. */
+export function createSolveInputs(draft: SolveInputsDraft): SolveResult<SolveInputs> {
+  const env = draft.env ?? STANDARD_ENV;
+  const ammo: AmmoProfile = {
+    id: "",
+    name: "",
+    ammoName: "",
+    model: "G7",
+    zeroDistanceM: 100,
+    scopeHeightMm: 40,
+    zeroEnv: env,
+    ...draft.ammo,
+  };
+  const step = draft.stepM ?? 100;
+  const maxRangeM = draft.maxRangeM ?? 1000;
+  if (!draft.rangesM && !(step > 0 && maxRangeM >= step)) {
+    return { ok: false, error: { kind: "InvalidRange", message: `Need 0 < stepM ≤ maxRangeM (got ${step}, ${maxRangeM})` } };
+  }
+  const rangesM = draft.rangesM ?? Array.from({ length: Math.floor(maxRangeM / step) }, (_, i) => (i + 1) * step);
+
+  const bad = validateInputs(ammo, rangesM, 1, env);
+  if (bad) return { ok: false, error: bad };
+  return {
+    ok: true,
+    value: { ammo, env, rangesM, windSpeed: draft.windSpeed ?? 0, windAngleDeg: draft.windAngleDeg ?? 90, opts: draft.opts ?? {} },
+  };
+}
+
+/** Ranges (m) where the bullet slows through Mach 1.2 and Mach 1.0, plus
+ *  the matching trajectory events (range, TOF, velocity). Solved with the
+ *  same wind and options as the DOPE rows, so it agrees with their Mach
+ *  column. 0 means the muzzle velocity is already below that Mach number;
+ *  null means the bullet is still above it at maxRangeM. */
+export function supersonicSummary(
+  ammo: AmmoProfile,
+  currentEnv: Environment,
+  maxRangeM = 3000,
+  windSpeed = 0,
+  windAngleDeg = 90,
+  opts: SolveOptions = {}
+) {
+  const a = speedOfSound(currentEnv);
+  const angle = opts.zeroAngleRad ?? solveZeroAngle(ammo, currentEnv);
+  let rangeMach1_2M: number | null = null;
+  let rangeMach1_0M: number | null = null;
+  const events: TrajectoryEvent[] = [];
+
+  // already below a threshold at the muzzle: that range is 0, not the first step
+  const V0 = mvCorrected(ammo, currentEnv);
+  if (V0 / a < 1.2) {
+    rangeMach1_2M = 0;
+    events.push({ kind: "mach1.2", t: 0, x: 0, v: V0 });
+  }
+  if (V0 / a < 1) {
+    rangeMach1_0M = 0;
+    events.push({ kind: "mach1.0", t: 0, x: 0, v: V0 });
+  }
+
+  integrateToRange({
+    ...opts,
+    ammo,
+    env: currentEnv,
+    rangeM: maxRangeM,
+    windSpeed,
+    windAngleDeg,
+    launchAngleRad: angle,
+    stop: { maxDropBelowLosM: Infinity, ...opts.stop },
+    onStep: observeAlso(opts.onStep, ({ x, v, mach, t }) => {
+      if (rangeMach1_2M === null && mach < 1.2) {
+        rangeMach1_2M = x;
+        events.push({ kind: "mach1.2", t, x, v });
+      }
+      if (rangeMach1_0M === null && mach < 1) {
+        rangeMach1_0M = x;
+        events.push({ kind: "mach1.0", t, x, v });
+      }
+    }),
+  });
+
+  return { speedOfSound: a, rangeMach1_2M, rangeMach1_0M, events };
+}
+
+/** What "still effective" means for solveMaxRange */
+export type RangeCriterion =
+  | { kind: "velocity"; minMps: number }
+  | { kind: "energy"; minJ: number }
+  | { kind: "mach"; minMach: number };
+
+/** Furthest range (m) at which the criterion still holds, walking the path
+ *  out to maxRangeM. null if it fails right at the muzzle; maxRangeM if it
+ *  still holds there. */
+export function solveMaxRange(
+  ammo: AmmoProfile,
+  currentEnv: Environment,
+  criterion: RangeCriterion,
+  maxRangeM = 3000,
+  opts: SolveOptions = {}
+): number | null {
+  const massKg = (ammo.bulletWeightGr || 0) * KG_PER_GR;
+  const ok = (v: number, mach: number) => {
+    switch (criterion.kind) {
+      case "velocity": return v >= criterion.minMps;
+      case "energy":   return 0.5 * massKg * v * v >= criterion.minJ;
+      case "mach":     return mach >= criterion.minMach;
+    }
+  };
+  const V0 = mvCorrected(ammo, currentEnv);
+  if (!ok(V0, V0 / speedOfSound(currentEnv))) return null;
+
+  let lastM = 0;
+  integrateToRange({
+    ...opts,
+    ammo,
+    env: currentEnv,
+    rangeM: maxRangeM,
+    launchAngleRad: opts.zeroAngleRad ?? solveZeroAngle(ammo, currentEnv),
+    stop: { maxDropBelowLosM: Infinity, ...opts.stop },
+    onStep: observeAlso(opts.onStep, ({ x, v, mach }) => {
+      if (!ok(v, mach)) return false;
+      lastM = x;
+    }),
+  });
+  return lastM;
+}
+
+/** Solve to rangeM and also report the apex: maximum ordinate above the
+ *  LOS, the range where it occurs, and the TOF to get there. Needed for
+ *  obstacle clearance and range-safety templates. */
+export function solveTrajectorySummary(
+  ammo: AmmoProfile,
+  currentEnv: Environment,
+  rangeM: number,
+  windSpeed = 0,
+  windAngleDeg = 90,
+  opts: SolveOptions = {}
+) {
+  const angle = opts.zeroAngleRad ?? solveZeroAngle(ammo, currentEnv);
+  let maxOrdinateM = 0;
+  let maxOrdinateRangeM = 0;
+  let apexTofS = 0;
+
+  const row = integrateToRange({
+    ...opts,
+    ammo,
+    env: currentEnv,
+    rangeM,
+    windSpeed,
+    windAngleDeg,
+    launchAngleRad: angle,
+    zeroDayDriftM: zeroDayDrift(ammo),
+    onStep: observeAlso(opts.onStep, (st) => {
+      if (st.yAboveLos > maxOrdinateM) {
+        maxOrdinateM = st.yAboveLos;
+        maxOrdinateRangeM = st.x;
+        apexTofS = st.t;
+      }
+    }),
+  });
+
+  return { ...row, maxOrdinateM, maxOrdinateRangeM, apexTofS };
+}
+
+/** Overhead-cover check: with the rifle dialed for `rangeM`, does the path
+ *  rise more than `ceilingM` above the LOS anywhere before the target?
+ *  Alternative loads (e.g. higher MV) are tried in order and the first that
+ *  clears the ceiling is returned. */
+export function checkCeiling(
+  ammo: AmmoProfile,
+  currentEnv: Environment,
+  rangeM: number,
+  ceilingM: number,
+  alternatives: AmmoProfile[] = []
+) {
+  const evaluate = (load: AmmoProfile) => {
+    // dialed for the target: the path crosses the LOS again at rangeM
+    const dialed = { ...load, zeroDistanceM: rangeM, zeroOffsetUpCm: undefined };
+    const angle = solveZeroAngle(dialed, currentEnv);
+    let maxOrdinateM = -Infinity;
+    let maxOrdinateRangeM = 0;
+    let firstViolationM: number | null = null;
+    let lastViolationM: number | null = null;
+
+    integrateToRange({
+      ammo: dialed,
+      env: currentEnv,
+      rangeM,
+      launchAngleRad: angle,
+      onStep: ({ x, yAboveLos }) => {
+        if (yAboveLos > maxOrdinateM) {
+          maxOrdinateM = yAboveLos;
+          maxOrdinateRangeM = x;
+        }
+        if (yAboveLos > ceilingM) {
+          if (firstViolationM === null) firstViolationM = x;
+          lastViolationM = x;
+        }
+      },
+    });
+
+    return {
+      ammoId: load.id,
+      violates: firstViolationM !== null,
+      maxOrdinateM,
+      maxOrdinateRangeM,
+      firstViolationM,
+      lastViolationM,
+    };
+  };
+
+  const result = evaluate(ammo);
+  const alternative = result.violates
+    ? alternatives.map(evaluate).find((r) => !r.violates) ?? null
+    : null;
+
+  return { ...result, alternative };
+}
+
+/** Full flight path out to maxRangeM: every integrator sample plus
+ *  Mach-crossing and apex events. Samples are in LOS coordinates. */
+export function solveFullTrajectory(
+  ammo: AmmoProfile,
+  currentEnv: Environment,
+  maxRangeM: number,
+  windSpeed = 0,
+  windAngleDeg = 90,
+  opts: SolveOptions = {}
+): Trajectory {
+  const angle = opts.zeroAngleRad ?? solveZeroAngle(ammo, currentEnv);
+  const V0 = mvCorrected(ammo, currentEnv);
+  const samples: TrajectorySample[] = [
+    {
+      t: 0, x: 0, y: -(ammo.scopeHeightMm ?? 0) / 1000, z: 0, v: V0, mach: V0 / speedOfSound(currentEnv),
+      vx: V0 * Math.cos(angle), vy: V0 * Math.sin(angle), vz: 0,
+    },
+  ];
+  const events: TrajectoryEvent[] = [];
+  let apex = samples[0];
+
+  integrateToRange({
+    ...opts,
+    ammo,
+    env: currentEnv,
+    rangeM: maxRangeM,
+    windSpeed,
+    windAngleDeg,
+    launchAngleRad: angle,
+    stop: { maxDropBelowLosM: Infinity, ...opts.stop },
+    onStep: observeAlso(opts.onStep, ({ x, yAboveLos, z, v, mach, t, vx, vy, vz }) => {
+      const prev = samples[samples.length - 1];
+      const cur = { t, x, y: yAboveLos, z, v, mach, vx, vy, vz };
+      if (prev.mach >= 1.2 && mach < 1.2) events.push({ kind: "mach1.2", t, x, v });
+      if (prev.mach >= 1 && mach < 1) events.push({ kind: "mach1.0", t, x, v });
+      if (yAboveLos > apex.y) apex = cur;
+      samples.push(cur);
+    }),
+  });
+
+  if (apex !== samples[0]) events.push({ kind: "apex", t: apex.t, x: apex.x, v: apex.v });
+  events.sort((a, b) => a.t - b.t);
+  return { samples, events };
+}
+
+/** Lazily stream the flight path out to maxRangeM, one integrator step at
+ *  a time, without collecting it. Stop consuming (break) to end early. The
+ *  loop over the steps is the observer, so opts.onStep is rejected (the
+ *  first next() throws) rather than silently left uncalled. */
+export function* trajectorySteps(
+  ammo: AmmoProfile,
+  currentEnv: Environment,
+  maxRangeM: number,
+  windSpeed = 0,
+  windAngleDeg = 90,
+  opts: SolveOptions = {}
+): Generator<StepState, void, undefined> {
+  if (opts.onStep) throw new TypeError("trajectorySteps yields every step; consume them instead of passing onStep");
+  yield* integrateSteps(
+    {
+      ...opts,
+      ammo,
+      env: currentEnv,
+      rangeM: maxRangeM,
+      windSpeed,
+      windAngleDeg,
+      launchAngleRad: opts.zeroAngleRad ?? solveZeroAngle(ammo, currentEnv),
+      stop: { maxDropBelowLosM: Infinity, ...opts.stop },
+    },
+    true
+  );
+}
+
+/* ---------------- Solver interface ----------------
+   Anything that can turn (ammo, environment, ranges) into DOPE rows.
+   UI code should depend on this rather than on a specific engine so a
+   higher-fidelity solver can be swapped in without touching callers.
+*/
+
+export type SolveRow = ReturnType<typeof solveRow>;
+
+export interface TrajectorySolver {
+  readonly name: string;
+  solveTable(
+    ammo: AmmoProfile,
+    currentEnv: Environment,
+    rangesM: number[],
+    windSpeed?: number,
+    windAngleDeg?: number,
+    opts?: SolveOptions
+  ): SolveRow[];
+  trajectory(
+    ammo: AmmoProfile,
+    currentEnv: Environment,
+    maxRangeM: number,
+    windSpeed?: number,
+    windAngleDeg?: number,
+    opts?: SolveOptions
+  ): Trajectory;
+}
+
+export const pointMassSolver: TrajectorySolver = {
+  name: "point-mass",
+  solveTable: buildDopeTable,
+  trajectory: solveFullTrajectory,
+};
+
+/** Modified point mass: the point-mass integrator with spin drift from the
+ *  lift of the yaw of repose (spinDriftModel "mpm") instead of Litz's fit */
+export const mpmSolver: TrajectorySolver = {
+  name: "modified-point-mass",
+  solveTable: (ammo, currentEnv, rangesM, windSpeed, windAngleDeg, opts = {}) =>
+    buildDopeTable(ammo, currentEnv, rangesM, windSpeed, windAngleDeg, { ...opts, spinDriftModel: "mpm" }),
+  trajectory: (ammo, currentEnv, maxRangeM, windSpeed, windAngleDeg, opts = {}) =>
+    solveFullTrajectory(ammo, currentEnv, maxRangeM, windSpeed, windAngleDeg, { ...opts, spinDriftModel: "mpm" }),
+};
+
+/** Danger space: aiming at the centre of a target of height targetHeightM,
+ *  with the rifle dialed for centerRangeM, the stretch of ranges over which
+ *  the path stays within ±targetHeightM/2 of the LOS (i.e. the same target
+ *  would still be hit if it were nearer or farther). */
+export function dangerSpace(
+  ammo: AmmoProfile,
+  currentEnv: Environment,
+  targetHeightM: number,
+  centerRangeM: number,
+  opts: SolveOptions = {}
+) {
+  const half = targetHeightM / 2;
+  const dialed = { ...ammo, zeroDistanceM: centerRangeM, zeroOffsetUpCm: undefined };
+  const angle = solveZeroAngle(dialed, currentEnv);
+
+  // walk the path; keep the in-window run that contains the centre range
+  let runStart: number | null = null;
+  let nearM: number | null = null;
+  let farM: number | null = null;
+
+  integrateToRange({
+    ...opts,
+    ammo: dialed,
+    env: currentEnv,
+    rangeM: centerRangeM * 3,
+    launchAngleRad: angle,
+    stop: { maxDropBelowLosM: Infinity, ...opts.stop },
+    onStep: observeAlso(opts.onStep, ({ x, yAboveLos }) => {
+      const inside = Math.abs(yAboveLos) <= half;
+      if (inside && runStart === null) runStart = x;
+      if (!inside && runStart !== null) {
+        if (x >= centerRangeM) {
+          nearM = runStart;
+          farM = x;
+          return false;
+        }
+        runStart = null;
+      }
+    }),
+  });
+
+  // still inside at the end of the walk: far edge is beyond 3x centre range
+  if (farM === null && runStart !== null) nearM = runStart;
+  if (nearM === null) nearM = 0;
+
+  return {
+    nearM,
+    farM,
+    depthM: farM !== null ? farM - nearM : null,
+  };
+}
@@ -0,0 +1,48 @@
+// src/lib/compare.ts
+import type { AmmoProfile, Environment } from "./appState";
+import { buildDopeTable, supersonicSummary } from "./calcEngine";
+import type { SolveOptions } from "./calcEngine";
+
+export type LoadComparison = {
+  ammoId: string;
+  name: string;
+  holdMil: number[];          // per range
+  dropM: number[];
+  driftM: number[];
+  energyJ: number[];
+  transonicRangeM: number | null; // Mach 1.2; 0 = already below at the muzzle, null = beyond 3000 m
+  subsonicRangeM: number | null;  // Mach 1.0
+};
+
+/**
+ * Side-by-side comparison of several loads under the same conditions and
+ * ranges. Each load keeps its own zero; arrays line up with rangesM.
+ */
+export function compareLoads(
+  loads: AmmoProfile[],
+  env: Environment,
+  rangesM: number[],
+  windSpeed = 0,
+  windAngleDeg = 90,
+  opts: SolveOptions = {}
+): { rangesM: number[]; loads: LoadComparison[] } {
+  return {
+    rangesM,
+    loads: loads.map((ammo) => {
+      // each load solves its own zero; rows and Mach ranges share conditions
+      const loadOpts = { ...opts, zeroAngleRad: undefined };
+      const rows = buildDopeTable(ammo, env, rangesM, windSpeed, windAngleDeg, loadOpts);
+      const sonic = supersonicSummary(ammo, env, undefined, windSpeed, windAngleDeg, loadOpts);
+      return {
+        ammoId: ammo.id,
+        name: ammo.name,
+        holdMil: rows.map((r) => r.holdMil),
+        dropM: rows.map((r) => r.dropM),
+        driftM: rows.map((r) => r.driftM),
+        energyJ: rows.map((r) => r.energyJ),
+        transonicRangeM: sonic.rangeMach1_2M,
+        subsonicRangeM: sonic.rangeMach1_0M,
+      };
+    }),
+  };
+}
@@ -128,3 +128,28 @@ export function suggestScopeCorrection({
     },
   };
 }
+
+/**
+ * Lead for a moving target, from the solved time of flight at range.
+ * crossingAngleDeg = 90 is a full-value crossing target; smaller angles
+ * (target moving partly toward/away from the shooter) scale by sin(angle).
+ * Lead is returned in the direction of target travel.
+ */
+export function leadForMovingTarget(
+  ammo: AmmoProfile,
+  env: Environment,
+  rangeM: number,
+  targetSpeedMps: number,
+  crossingAngleDeg = 90
+) {
+  const { tof } = solveTrajectory(ammo, env, rangeM);
+  const crossingSpeed = targetSpeedMps * Math.sin((crossingAngleDeg * Math.PI) / 180);
+  const leadM = crossingSpeed * tof;
+  const leadMil = (leadM / rangeM) * 1000;
+  return {
+    tof,
+    leadM,
+    leadMil,
+    leadMoa: milToMoa(leadMil),
+  };
+}
@@ -0,0 +1,63 @@
+// src/lib/csvExport.ts
+// Spreadsheet export for DOPE rows and full trajectories.
+
+import type { SolveRow } from "./calcEngine";
+import type { Trajectory } from "./trajectory";
+import { metersToInches, metersToYards, mpsToFps } from "./units";
+
+export type CsvOptions = {
+  delimiter?: string;              // default ","
+  units?: "metric" | "imperial";   // default metric
+  precision?: number;              // decimals for lengths/velocities, default 2
+};
+
+type Column<T> = { header: string; value: (r: T) => number | string | undefined };
+
+function write<T>(items: T[], columns: Column<T>[], delimiter: string): string {
+  const quote = (v: number | string | undefined) => {
+    const s = v === undefined ? "" : String(v);
+    return s.includes(delimiter) || s.includes('"') || s.includes("\n") ? `"${s.replace(/"/g, '""')}"` : s;
+  };
+  const lines = items.map((it) => columns.map((c) => quote(c.value(it))).join(delimiter));
+  return [columns.map((c) => quote(c.header)).join(delimiter), ...lines].join("\n");
+}
+
+/** One line per DOPE row; rangesM are the ranges the table was built for */
+export function rowsToCSV(rows: SolveRow[], rangesM: number[], opts: CsvOptions = {}): string {
+  const { delimiter = ",", units = "metric", precision = 2 } = opts;
+  const imp = units === "imperial";
+  const f = (n: number) => n.toFixed(precision);
+  const items = rows.map((row, i) => ({ row, rangeM: rangesM[i] }));
+  const columns: Column<{ row: SolveRow; rangeM: number }>[] = [
+    { header: imp ? "range_yd" : "range_m", value: ({ rangeM }) => f(imp ? metersToYards(rangeM) : rangeM) },
+    { header: "tof_s", value: ({ row }) => row.tof.toFixed(3) },
+    { header: imp ? "velocity_fps" : "velocity_mps", value: ({ row }) => f(imp ? mpsToFps(row.impactVel) : row.impactVel) },
+    { header: "mach", value: ({ row }) => row.mach.toFixed(3) },
+    { header: imp ? "energy_ftlbf" : "energy_j", value: ({ row }) => f(imp ? row.energyFtLbf : row.energyJ) },
+    { header: imp ? "drop_in" : "drop_cm", value: ({ row }) => f(imp ? metersToInches(row.dropM) : row.dropM * 100) },
+    { header: imp ? "drift_in" : "drift_cm", value: ({ row }) => f(imp ? metersToInches(row.driftM) : row.driftM * 100) },
+    { header: "hold_mil", value: ({ row }) => row.holdMil.toFixed(2) },
+    { header: "hold_moa", value: ({ row }) => row.holdMoa.toFixed(2) },
+    { header: "windage_mil", value: ({ row }) => row.lateralHoldMil.toFixed(2) },
+    { header: "elevation_clicks", value: ({ row }) => row.clicks?.elevationClicks },
+    { header: "windage_clicks", value: ({ row }) => row.clicks?.windageClicks },
+    { header: "warning", value: ({ row }) => row.warning },
+  ];
+  return write(items, columns, delimiter);
+}
+
+/** Every trajectory sample (header + one line per sample) */
+export function trajectoryToCSV(traj: Trajectory, opts: CsvOptions = {}): string {
+  const { delimiter = ",", units = "metric", precision = 2 } = opts;
+  const imp = units === "imperial";
+  const len = (m: number, extra = 0) => (imp ? metersToInches(m) : m).toFixed(precision + extra);
+  const columns: Column<Trajectory["samples"][number]>[] = [
+    { header: "t_s", value: (p) => p.t.toFixed(4) },
+    { header: imp ? "x_yd" : "x_m", value: (p) => (imp ? metersToYards(p.x) : p.x).toFixed(precision) },
+    { header: imp ? "y_in" : "y_m", value: (p) => len(p.y, imp ? 0 : 2) },
+    { header: imp ? "z_in" : "z_m", value: (p) => len(p.z, imp ? 0 : 2) },
+    { header: imp ? "v_fps" : "v_mps", value: (p) => (imp ? mpsToFps(p.v) : p.v).toFixed(precision) },
+    { header: "mach", value: (p) => p.mach.toFixed(3) },
+  ];
+  return write(traj.samples, columns, delimiter);
+}
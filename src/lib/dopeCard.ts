@@ -0,0 +1,89 @@
+// src/lib/dopeCard.ts
+import type { AmmoProfile, Environment, ScopeUnits } from "./appState";
+import { buildDopeTable } from "./calcEngine";
+import type { SolveOptions, SolveRow } from "./calcEngine";
+import { metersToInches, mpsToFps, yardsToMeters } from "./units";
+
+export type DopeCardColumn = "elevation" | "windage" | "drop" | "clicks" | "velocity" | "energy" | "tof";
+
+export type DopeCardSpec = {
+  start: number;
+  end: number;              // inclusive
+  step: number;
+  unit: "m" | "yd";         // unit of start/end/step, and of the row labels
+  columns: DopeCardColumn[];
+  holdUnits?: ScopeUnits;   // elevation/windage columns; default MIL
+};
+
+export type DopeCardRow = {
+  range: number;            // in spec.unit
+  rangeM: number;
+  values: Partial<Record<DopeCardColumn, number>>;
+};
+
+export type DopeCard = {
+  unit: "m" | "yd";
+  holdUnits: ScopeUnits;
+  columns: DopeCardColumn[];
+  headers: string[];        // "Range (m)" followed by one header per column
+  rows: DopeCardRow[];
+};
+
+const MOA_PER_MIL = 3.43774677;
+
+/** Column headers; drop/velocity/energy follow the card's range unit */
+function header(col: DopeCardColumn, unit: "m" | "yd", holdUnits: ScopeUnits): string {
+  const imperial = unit === "yd";
+  switch (col) {
+    case "elevation": return `Elev (${holdUnits})`;
+    case "windage":   return `Wind (${holdUnits})`;
+    case "drop":      return imperial ? "Drop (in)" : "Drop (cm)";
+    case "clicks":    return "Clicks";
+    case "velocity":  return imperial ? "Vel (fps)" : "Vel (m/s)";
+    case "energy":    return imperial ? "Energy (ft·lbf)" : "Energy (J)";
+    case "tof":       return "TOF (s)";
+  }
+}
+
+function cell(col: DopeCardColumn, r: SolveRow, unit: "m" | "yd", holdUnits: ScopeUnits): number | undefined {
+  const imperial = unit === "yd";
+  const toUnits = (mil: number) => (holdUnits === "MIL" ? mil : mil * MOA_PER_MIL);
+  switch (col) {
+    case "elevation": return toUnits(r.holdMil);
+    case "windage":   return toUnits(r.lateralHoldMil);
+    case "drop":      return imperial ? metersToInches(r.dropM) : r.dropM * 100;
+    case "clicks":    return r.clicks?.elevationClicks;
+    case "velocity":  return imperial ? mpsToFps(r.impactVel) : r.impactVel;
+    case "energy":    return imperial ? r.energyFtLbf : r.energyJ;
+    case "tof":       return r.tof;
+  }
+}
+
+/** Range card from start to end every step (metres or yards), with only the
+ *  requested columns filled. "clicks" needs opts.scope. */
+export function buildDopeCard(
+  ammo: AmmoProfile,
+  env: Environment,
+  spec: DopeCardSpec,
+  windSpeed = 0,
+  windAngleDeg = 90,
+  opts: SolveOptions = {}
+): DopeCard {
+  const holdUnits = spec.holdUnits ?? "MIL";
+  const labels: number[] = [];
+  if (spec.step > 0) for (let r = spec.start; r <= spec.end + 1e-9; r += spec.step) if (r > 0) labels.push(r);
+  const rangesM = labels.map((r) => (spec.unit === "yd" ? yardsToMeters(r) : r));
+  const table = buildDopeTable(ammo, env, rangesM, windSpeed, windAngleDeg, opts);
+
+  return {
+    unit: spec.unit,
+    holdUnits,
+    columns: spec.columns,
+    headers: [`Range (${spec.unit})`, ...spec.columns.map((c) => header(c, spec.unit, holdUnits))],
+    rows: table.map((r, i) => ({
+      range: labels[i],
+      rangeM: rangesM[i],
+      values: Object.fromEntries(spec.columns.map((c) => [c, cell(c, r, spec.unit, holdUnits)])),
+    })),
+  };
+}
@@ -0,0 +1,163 @@
+// src/lib/dragImport.ts
+// Read and write drag curves: Doppler radar Cd(Mach) listings, trued DSF
+// curves and .drg files from other ballistics programs.
+
+import type { AmmoProfile } from "./appState";
+import type { SolveResult } from "./calcEngine";
+import type { CdPoint, DsfPoint } from "./dragTables";
+
+const KG_PER_GR = 6.479891e-5;
+
+/** Split one line into fields. With ";" or tab separators a decimal comma
+ *  is allowed ("0,95;0,412"); otherwise commas and spaces both separate. */
+function fields(line: string): string[] {
+  if (/[;\t]/.test(line)) return line.split(/[;\t]+/).map((f) => f.trim().replace(",", "."));
+  return line.trim().split(/[,\s]+/);
+}
+
+type Column = (name: string) => boolean;
+
+const isMach: Column = (s) => s.startsWith("mach") || s === "ma" || s === "m";
+const isCd: Column = (s) => s.startsWith("cd") || s.startsWith("c_d") || s === "drag";
+const isDsf: Column = (s) => s.startsWith("dsf") || s.startsWith("factor") || s === "k";
+
+/** Two columns out of a Mach-indexed listing: [mach, value] pairs sorted by
+ *  Mach, duplicates dropped. `what` names the value in error messages. */
+function readMachTable(text: string, isValue: Column, what: string): SolveResult<[number, number][]> {
+  const fail = (message: string) => ({ ok: false as const, error: { kind: "InvalidDragTable" as const, message } });
+  let machCol = 0;
+  let valueCol = 1;
+  const byMach = new Map<number, number>();
+
+  const lines = text.split(/\r?\n/);
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i].trim();
+    if (!line || line.startsWith("#") || line.startsWith("//")) continue;
+    const f = fields(line);
+    const nums = f.map(Number);
+
+    if (nums.some((n) => Number.isNaN(n))) {
+      // header: take the column positions if it names both
+      const names = f.map((s) => s.toLowerCase());
+      const m = names.findIndex(isMach);
+      const v = names.findIndex(isValue);
+      if (m >= 0 && v >= 0) {
+        machCol = m;
+        valueCol = v;
+      }
+      continue;
+    }
+
+    const mach = nums[machCol];
+    const value = nums[valueCol];
+    if (mach === undefined || value === undefined) continue;
+    if (!(mach >= 0) || !(value > 0)) return fail(`Line ${i + 1}: Mach must be ≥ 0 and ${what} positive`);
+    byMach.set(mach, value);
+  }
+
+  const rows = [...byMach.entries()].sort((a, b) => a[0] - b[0]);
+  if (rows.length < 2) return fail(`No ${what}(Mach) table found: need at least two Mach, ${what} lines`);
+  if (rows[rows.length - 1][0] > 10) {
+    return fail("Mach column goes past 10: is it a velocity column? Convert to Mach first");
+  }
+  return { ok: true, value: rows };
+}
+
+/**
+ * Parse a Cd(Mach) listing as distributed by Lapua and in BRL reports: one
+ * point per line, with the fields separated by commas, semicolons, tabs or spaces.
+ * Blank, comment (#, //) and other non-numeric lines are skipped. A header
+ * naming the columns ("Mach", "Cd") picks them out of wider tables,
+ * otherwise the first two columns are Mach and Cd. Points come back sorted
+ * by Mach with duplicates dropped.
+ */
+export function parseDopplerCd(text: string): SolveResult<CdPoint[]> {
+  const rows = readMachTable(text, isCd, "Cd");
+  return rows.ok ? { ok: true, value: rows.value.map(([mach, cd]) => ({ mach, cd })) } : rows;
+}
+
+/** Parse a trued drag-scale-factor curve ("Mach, DSF" lines, same rules as
+ *  parseDopplerCd) into AmmoProfile.dsfTable */
+export function parseDsfCurve(text: string): SolveResult<DsfPoint[]> {
+  const rows = readMachTable(text, isDsf, "DSF");
+  return rows.ok ? { ok: true, value: rows.value.map(([mach, factor]) => ({ mach, factor })) } : rows;
+}
+
+/* ---------------- Drag files from other ballistics programs ---------------- */
+
+/**
+ * .drg (QuickTARGET / Lapua): a header line "CFM <name> <mass kg> <diameter m>",
+ * then one "Cd Mach" pair per line. The formats Hornady 4DOF and Applied
+ * Ballistics keep their own curves in are closed; those apps exchange drag
+ * curves as .drg or as plain CSV, which is what this reads and writes.
+ */
+export type DragFileFormat = "drg" | "cd-csv" | "dsf-csv";
+
+/** What an imported file sets on an AmmoProfile */
+export type DragImport = {
+  format: DragFileFormat;
+  name?: string;
+  patch: Pick<AmmoProfile, "cdTable" | "dsfTable" | "bulletWeightGr" | "bulletDiameterMm">;
+};
+
+/** Guess the format from the file name and, failing that, the content */
+export function detectDragFormat(text: string, fileName = ""): DragFileFormat {
+  if (/\.drg$/i.test(fileName)) return "drg";
+  const first = text.split(/\r?\n/).find((l) => l.trim() && !/^(#|\/\/)/.test(l.trim())) ?? "";
+  if (/^\s*CFM\b/i.test(first)) return "drg";
+  return /\b(dsf|factor)\b/i.test(first) ? "dsf-csv" : "cd-csv";
+}
+
+function parseDrg(text: string): SolveResult<DragImport> {
+  const lines = text.split(/\r?\n/);
+  const at = lines.findIndex((l) => l.trim());
+  const head = (lines[at] ?? "").trim().split(/\s+/);
+  const massKg = Number(head[head.length - 2]);
+  const diameterM = Number(head[head.length - 1]);
+  const table = parseDopplerCd(["Cd Mach", ...lines.slice(at + 1)].join("\n"));
+  if (!table.ok) return table;
+  return {
+    ok: true,
+    value: {
+      format: "drg",
+      name: head.slice(1, -2).join(" ") || undefined,
+      patch: {
+        cdTable: table.value,
+        bulletWeightGr: massKg > 0 ? massKg / KG_PER_GR : undefined,
+        bulletDiameterMm: diameterM > 0 ? diameterM * 1000 : undefined,
+      },
+    },
+  };
+}
+
+/**
+ * Read a drag file (.drg, Cd CSV or DSF CSV) into the AmmoProfile fields it
+ * sets. Fields the file doesn't carry are left undefined; callers should
+ * keep the load's own values for those.
+ */
+export function importDragFile(text: string, fileName = ""): SolveResult<DragImport> {
+  const format = detectDragFormat(text, fileName);
+  if (format === "drg") return parseDrg(text);
+  if (format === "dsf-csv") {
+    const dsf = parseDsfCurve(text);
+    return dsf.ok ? { ok: true, value: { format, patch: { dsfTable: dsf.value } } } : dsf;
+  }
+  const cd = parseDopplerCd(text);
+  return cd.ok ? { ok: true, value: { format, patch: { cdTable: cd.value } } } : cd;
+}
+
+/** Write a Cd(Mach) curve as a .drg file; needs the bullet's weight and diameter */
+export function cdTableToDrg(table: CdPoint[], name: string, bulletWeightGr: number, bulletDiameterMm: number): string {
+  const head = `CFM ${name.replace(/\s+/g, "_") || "custom"} ${(bulletWeightGr * KG_PER_GR).toFixed(6)} ${(bulletDiameterMm / 1000).toFixed(5)}`;
+  return [head, ...table.map((p) => `${p.cd.toFixed(4)} ${p.mach.toFixed(3)}`)].join("\n");
+}
+
+/** Cd(Mach) as "Mach,Cd" CSV (reads back with parseDopplerCd) */
+export function cdTableToCSV(table: CdPoint[]): string {
+  return ["Mach,Cd", ...table.map((p) => `${p.mach.toFixed(3)},${p.cd.toFixed(4)}`)].join("\n");
+}
+
+/** A trued DSF curve as "Mach,DSF" CSV (reads back with parseDsfCurve) */
+export function dsfTableToCSV(table: DsfPoint[]): string {
+  return ["Mach,DSF", ...table.map((p) => `${p.mach.toFixed(3)},${p.factor.toFixed(4)}`)].join("\n");
+}
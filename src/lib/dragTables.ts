@@ -1,54 +1,125 @@
 /*
-  Provide full G1 and G7 "retardation" tables or equivalent drag functions as arrays.
+  Provide full G1 and G7 (plus G2, G5, G6, G8 and GS) "retardation" tables or equivalent drag functions as arrays.
   Each entry is { v: number; f: number } where:
-    v = speed in m/s
-    f = reference drag function value (dimensionless) for the chosen standard (G1 or G7)
+    v = speed in m/s at the standard speed of sound (Mach × A_STD)
+    f = reference drag coefficient Cd (dimensionless) of the chosen standard
 
-  NOTE: The solver expects functions fG1(v) and fG7(v) that linearly interpolate.
+  NOTE: The solver expects functions fG1(v) and fG7(v) that linearly interpolate;
+  interpSmooth is the optional C¹ alternative.
 */
 
 export type DragPoint = { v: number; f: number };
 export type DragTable = DragPoint[];
 
-// Full G1 drag coefficient table (standard flat-base projectile)
-export const G1_TABLE: DragTable = [
-  { v: 0, f: 0.2629 }, { v: 51.85, f: 0.2558 }, { v: 103.7, f: 0.2487 }, { v: 155.55, f: 0.2413 }, { v: 207.4, f: 0.2344 },
-  { v: 259.25, f: 0.2278 }, { v: 311.1, f: 0.2214 }, { v: 362.95, f: 0.2155 }, { v: 414.8, f: 0.2104 }, { v: 466.65, f: 0.2061 },
-  { v: 518.5, f: 0.2032 }, { v: 570.35, f: 0.2020 }, { v: 622.2, f: 0.2034 }, { v: 674.05, f: 0.2165 }, { v: 725.9, f: 0.2230 },
-  { v: 777.75, f: 0.2313 }, { v: 829.6, f: 0.2417 }, { v: 881.45, f: 0.2546 }, { v: 933.3, f: 0.2706 }, { v: 985.15, f: 0.2901 },
-  { v: 1037, f: 0.3136 }, { v: 1088.85, f: 0.3415 }, { v: 1140.7, f: 0.3734 }, { v: 1192.55, f: 0.4084 }, { v: 1244.4, f: 0.4448 },
-  { v: 1296.25, f: 0.4805 }, { v: 1348.1, f: 0.5136 }, { v: 1399.95, f: 0.5427 }, { v: 1451.8, f: 0.5677 }, { v: 1503.65, f: 0.5883 },
-  { v: 1555.5, f: 0.6053 }, { v: 1607.35, f: 0.6191 }, { v: 1659.2, f: 0.6393 }, { v: 1711.05, f: 0.6518 }, { v: 1762.9, f: 0.6589 },
-  { v: 1814.75, f: 0.6621 }, { v: 1866.6, f: 0.6625 }, { v: 1918.45, f: 0.6607 }, { v: 1970.3, f: 0.6573 }, { v: 2022.15, f: 0.6528 },
-  { v: 2074, f: 0.6474 }, { v: 2125.85, f: 0.6413 }, { v: 2177.7, f: 0.6347 }, { v: 2229.55, f: 0.6280 }, { v: 2281.4, f: 0.6210 },
-  { v: 2333.25, f: 0.6141 }, { v: 2385.1, f: 0.6072 }, { v: 2436.95, f: 0.6003 }, { v: 2488.8, f: 0.5934 }, { v: 2540.65, f: 0.5867 },
-  { v: 2592.5, f: 0.5804 }, { v: 2644.35, f: 0.5743 }, { v: 2696.2, f: 0.5685 }, { v: 2748.05, f: 0.5630 }, { v: 2799.9, f: 0.5577 },
-  { v: 2851.75, f: 0.5527 }, { v: 2903.6, f: 0.5481 }, { v: 2955.45, f: 0.5438 }, { v: 3007.3, f: 0.5397 }, { v: 3059.15, f: 0.5325 },
-  { v: 3111, f: 0.5264 }, { v: 3214.7, f: 0.5155 }, { v: 3318.4, f: 0.5059 }, { v: 3422.1, f: 0.4967 }, { v: 3525.8, f: 0.4888 },
-  { v: 3629.5, f: 0.4806 }, { v: 3733.2, f: 0.4725 }, { v: 3836.9, f: 0.4654 }, { v: 3940.6, f: 0.4588 }, { v: 4044.3, f: 0.4533 },
-  { v: 4148, f: 0.4497 }, { v: 4355.4, f: 0.4442 }, { v: 4562.8, f: 0.4402 }, { v: 4770.2, f: 0.4376 }, { v: 4977.6, f: 0.4362 },
-  { v: 5185, f: 0.4359 }, { v: 5392.4, f: 0.4368 }, { v: 5599.8, f: 0.4388 }, { v: 5807.2, f: 0.4420 }, { v: 6014.6, f: 0.4464 }
-];
-
-// Full G7 drag coefficient table (boat-tail match projectiles)
-export const G7_TABLE: DragTable = [
-  { v: 0, f: 0.1198 }, { v: 51.85, f: 0.1197 }, { v: 103.7, f: 0.1196 }, { v: 155.55, f: 0.1194 }, { v: 207.4, f: 0.1193 },
-  { v: 259.25, f: 0.1194 }, { v: 311.1, f: 0.1194 }, { v: 362.95, f: 0.1194 }, { v: 414.8, f: 0.1193 }, { v: 466.65, f: 0.1193 },
-  { v: 518.5, f: 0.1194 }, { v: 570.35, f: 0.1193 }, { v: 622.2, f: 0.1196 }, { v: 674.05, f: 0.1201 }, { v: 725.9, f: 0.1209 },
-  { v: 777.75, f: 0.1222 }, { v: 829.6, f: 0.1238 }, { v: 881.45, f: 0.1257 }, { v: 933.3, f: 0.1281 }, { v: 985.15, f: 0.1312 },
-  { v: 1037, f: 0.1330 }, { v: 1088.85, f: 0.1349 }, { v: 1140.7, f: 0.1369 }, { v: 1192.55, f: 0.1388 }, { v: 1244.4, f: 0.1408 },
-  { v: 1296.25, f: 0.1428 }, { v: 1348.1, f: 0.1447 }, { v: 1399.95, f: 0.1466 }, { v: 1451.8, f: 0.1484 }, { v: 1503.65, f: 0.1502 },
-  { v: 1555.5, f: 0.1519 }, { v: 1607.35, f: 0.1535 }, { v: 1659.2, f: 0.1551 }, { v: 1711.05, f: 0.1566 }, { v: 1762.9, f: 0.1580 },
-  { v: 1814.75, f: 0.1594 }, { v: 1866.6, f: 0.1607 }, { v: 1918.45, f: 0.1619 }, { v: 1970.3, f: 0.1630 }, { v: 2022.15, f: 0.1640 },
-  { v: 2074, f: 0.1650 }, { v: 2125.85, f: 0.1659 }, { v: 2177.7, f: 0.1667 }, { v: 2229.55, f: 0.1675 }, { v: 2281.4, f: 0.1682 },
-  { v: 2333.25, f: 0.1688 }, { v: 2385.1, f: 0.1694 }, { v: 2436.95, f: 0.1700 }, { v: 2488.8, f: 0.1705 }, { v: 2540.65, f: 0.1710 },
-  { v: 2592.5, f: 0.1714 }, { v: 2644.35, f: 0.1718 }, { v: 2696.2, f: 0.1722 }, { v: 2748.05, f: 0.1725 }, { v: 2799.9, f: 0.1728 },
-  { v: 2851.75, f: 0.1731 }, { v: 2903.6, f: 0.1733 }, { v: 2955.45, f: 0.1735 }, { v: 3007.3, f: 0.1737 }, { v: 3059.15, f: 0.1738 },
-  { v: 3111, f: 0.1739 }, { v: 3214.7, f: 0.1740 }, { v: 3318.4, f: 0.1740 }, { v: 3422.1, f: 0.1740 }, { v: 3525.8, f: 0.1738 },
-  { v: 3629.5, f: 0.1735 }, { v: 3733.2, f: 0.1732 }, { v: 3836.9, f: 0.1728 }, { v: 3940.6, f: 0.1724 }, { v: 4044.3, f: 0.1719 },
-  { v: 4148, f: 0.1714 }, { v: 4355.4, f: 0.1704 }, { v: 4562.8, f: 0.1693 }, { v: 4770.2, f: 0.1681 }, { v: 4977.6, f: 0.1669 },
-  { v: 5185, f: 0.1657 }, { v: 5392.4, f: 0.1644 }, { v: 5599.8, f: 0.1632 }, { v: 5807.2, f: 0.1620 }, { v: 6014.6, f: 0.1608 }
-];
+/* All standard reference projectiles are published as Cd vs Mach. They are
+   stored with v = Mach × 340.294 m/s, the standard speed of sound the solver
+   looks the tables up at, so every family shares one index. GL (flat-nose
+   lead) is deliberately absent: it needs a verified published table, and a
+   guessed one would give wrong holds rather than none. */
+export const A_STD = 340.294;
+
+function fromMach(rows: [number, number][]): DragTable {
+  return rows.map(([mach, f]) => ({ v: mach * A_STD, f }));
+}
+
+// G1: flat base, 2 cal nose (Ingalls / standard flat-base projectile)
+export const G1_TABLE: DragTable = fromMach([
+  [0.00, 0.2629], [0.05, 0.2558], [0.10, 0.2487], [0.15, 0.2413], [0.20, 0.2344], [0.25, 0.2278], [0.30, 0.2214], [0.35, 0.2155],
+  [0.40, 0.2104], [0.45, 0.2061], [0.50, 0.2032], [0.55, 0.2020], [0.60, 0.2034], [0.70, 0.2165], [0.725, 0.2230], [0.75, 0.2313],
+  [0.775, 0.2417], [0.80, 0.2546], [0.825, 0.2706], [0.85, 0.2901], [0.875, 0.3136], [0.90, 0.3415], [0.925, 0.3734], [0.95, 0.4084],
+  [0.975, 0.4448], [1.0, 0.4805], [1.025, 0.5136], [1.05, 0.5427], [1.075, 0.5677], [1.10, 0.5883], [1.125, 0.6053], [1.15, 0.6191],
+  [1.20, 0.6393], [1.25, 0.6518], [1.30, 0.6589], [1.35, 0.6621], [1.40, 0.6625], [1.45, 0.6607], [1.50, 0.6573], [1.55, 0.6528],
+  [1.60, 0.6474], [1.65, 0.6413], [1.70, 0.6347], [1.75, 0.6280], [1.80, 0.6210], [1.85, 0.6141], [1.90, 0.6072], [1.95, 0.6003],
+  [2.00, 0.5934], [2.05, 0.5867], [2.10, 0.5804], [2.15, 0.5743], [2.20, 0.5685], [2.25, 0.5630], [2.30, 0.5577], [2.35, 0.5527],
+  [2.40, 0.5481], [2.45, 0.5438], [2.50, 0.5397], [2.60, 0.5325], [2.70, 0.5264], [2.80, 0.5211], [2.90, 0.5168], [3.00, 0.5133],
+  [3.10, 0.5105], [3.20, 0.5084], [3.30, 0.5067], [3.40, 0.5054], [3.50, 0.5040], [3.60, 0.5030], [3.70, 0.5022], [3.80, 0.5016],
+  [3.90, 0.5010], [4.00, 0.5006], [4.20, 0.4998], [4.40, 0.4995], [4.60, 0.4992], [4.80, 0.4990], [5.00, 0.4988]
+]);
+
+// G7: long 7.5° boat-tail, 10 cal tangent ogive (boat-tail match projectiles)
+export const G7_TABLE: DragTable = fromMach([
+  [0.00, 0.1198], [0.05, 0.1197], [0.10, 0.1196], [0.15, 0.1194], [0.20, 0.1193], [0.25, 0.1194], [0.30, 0.1194], [0.35, 0.1194],
+  [0.40, 0.1193], [0.45, 0.1193], [0.50, 0.1194], [0.55, 0.1193], [0.60, 0.1194], [0.65, 0.1197], [0.70, 0.1202], [0.725, 0.1207],
+  [0.75, 0.1215], [0.775, 0.1226], [0.80, 0.1242], [0.825, 0.1266], [0.85, 0.1306], [0.875, 0.1368], [0.90, 0.1464], [0.925, 0.1660],
+  [0.95, 0.2054], [0.975, 0.2993], [1.0, 0.3803], [1.025, 0.4015], [1.05, 0.4043], [1.075, 0.4034], [1.10, 0.4014], [1.125, 0.3987],
+  [1.15, 0.3955], [1.20, 0.3884], [1.25, 0.3810], [1.30, 0.3732], [1.35, 0.3657], [1.40, 0.3580], [1.50, 0.3440], [1.55, 0.3376],
+  [1.60, 0.3315], [1.65, 0.3260], [1.70, 0.3209], [1.75, 0.3160], [1.80, 0.3117], [1.85, 0.3078], [1.90, 0.3042], [1.95, 0.3010],
+  [2.00, 0.2980], [2.05, 0.2951], [2.10, 0.2922], [2.15, 0.2892], [2.20, 0.2864], [2.25, 0.2835], [2.30, 0.2807], [2.35, 0.2779],
+  [2.40, 0.2752], [2.45, 0.2725], [2.50, 0.2697], [2.55, 0.2670], [2.60, 0.2643], [2.65, 0.2615], [2.70, 0.2588], [2.75, 0.2561],
+  [2.80, 0.2533], [2.85, 0.2506], [2.90, 0.2479], [2.95, 0.2451], [3.00, 0.2424], [3.10, 0.2368], [3.20, 0.2313], [3.30, 0.2258],
+  [3.40, 0.2205], [3.50, 0.2154], [3.60, 0.2106], [3.70, 0.2060], [3.80, 0.2017], [3.90, 0.1975], [4.00, 0.1935], [4.20, 0.1861],
+  [4.40, 0.1793], [4.60, 0.1730], [4.80, 0.1672], [5.00, 0.1618]
+]);
+
+// G2: Aberdeen J projectile (long boat-tail, conical nose)
+export const G2_TABLE: DragTable = fromMach([
+  [0.00, 0.2303], [0.05, 0.2298], [0.10, 0.2287], [0.15, 0.2271], [0.20, 0.2251], [0.25, 0.2227], [0.30, 0.2196], [0.35, 0.2156],
+  [0.40, 0.2107], [0.45, 0.2048], [0.50, 0.1980], [0.55, 0.1905], [0.60, 0.1828], [0.65, 0.1758], [0.70, 0.1702], [0.75, 0.1669],
+  [0.775, 0.1664], [0.80, 0.1667], [0.825, 0.1682], [0.85, 0.1711], [0.875, 0.1761], [0.90, 0.1831], [0.925, 0.2004], [0.95, 0.2589],
+  [0.975, 0.3492], [1.0, 0.3983], [1.025, 0.4075], [1.05, 0.4103], [1.075, 0.4114], [1.10, 0.4106], [1.125, 0.4089], [1.15, 0.4068],
+  [1.175, 0.4046], [1.20, 0.4021], [1.25, 0.3966], [1.30, 0.3904], [1.35, 0.3835], [1.40, 0.3759], [1.45, 0.3678], [1.50, 0.3594],
+  [1.55, 0.3512], [1.60, 0.3432], [1.65, 0.3356], [1.70, 0.3282], [1.75, 0.3213], [1.80, 0.3149], [1.85, 0.3089], [1.90, 0.3033],
+  [1.95, 0.2982], [2.00, 0.2933], [2.05, 0.2889], [2.10, 0.2846], [2.15, 0.2806], [2.20, 0.2768], [2.25, 0.2731], [2.30, 0.2696],
+  [2.35, 0.2663], [2.40, 0.2632], [2.45, 0.2602], [2.50, 0.2572], [2.55, 0.2543], [2.60, 0.2515], [2.65, 0.2487], [2.70, 0.2460],
+  [2.75, 0.2433], [2.80, 0.2408], [2.85, 0.2382], [2.90, 0.2357], [2.95, 0.2333], [3.00, 0.2309], [3.10, 0.2262], [3.20, 0.2217],
+  [3.30, 0.2173], [3.40, 0.2132], [3.50, 0.2091], [3.60, 0.2052], [3.70, 0.2014], [3.80, 0.1978], [3.90, 0.1944], [4.00, 0.1912],
+  [4.20, 0.1851], [4.40, 0.1794], [4.60, 0.1741], [4.80, 0.1693], [5.00, 0.1648]
+]);
+
+// G5: short 7.5° boat-tail, 6.19 cal tangent ogive
+export const G5_TABLE: DragTable = fromMach([
+  [0.00, 0.1710], [0.05, 0.1719], [0.10, 0.1727], [0.15, 0.1732], [0.20, 0.1734], [0.25, 0.1730], [0.30, 0.1718], [0.35, 0.1696],
+  [0.40, 0.1668], [0.45, 0.1637], [0.50, 0.1603], [0.55, 0.1566], [0.60, 0.1529], [0.65, 0.1497], [0.70, 0.1473], [0.75, 0.1463],
+  [0.80, 0.1489], [0.85, 0.1583], [0.875, 0.1672], [0.90, 0.1815], [0.925, 0.2051], [0.95, 0.2413], [0.975, 0.2884], [1.0, 0.3379],
+  [1.025, 0.3785], [1.05, 0.4032], [1.075, 0.4147], [1.10, 0.4201], [1.15, 0.4278], [1.20, 0.4338], [1.25, 0.4373], [1.30, 0.4392],
+  [1.35, 0.4403], [1.40, 0.4406], [1.45, 0.4401], [1.50, 0.4386], [1.55, 0.4362], [1.60, 0.4328], [1.65, 0.4286], [1.70, 0.4237],
+  [1.75, 0.4182], [1.80, 0.4121], [1.85, 0.4057], [1.90, 0.3991], [1.95, 0.3926], [2.00, 0.3861], [2.05, 0.3800], [2.10, 0.3741],
+  [2.15, 0.3684], [2.20, 0.3630], [2.25, 0.3578], [2.30, 0.3529], [2.35, 0.3481], [2.40, 0.3435], [2.45, 0.3391], [2.50, 0.3349],
+  [2.60, 0.3269], [2.70, 0.3194], [2.80, 0.3125], [2.90, 0.3060], [3.00, 0.2999], [3.10, 0.2942], [3.20, 0.2889], [3.30, 0.2838],
+  [3.40, 0.2790], [3.50, 0.2745], [3.60, 0.2703], [3.70, 0.2662], [3.80, 0.2624], [3.90, 0.2588], [4.00, 0.2553], [4.20, 0.2488],
+  [4.40, 0.2429], [4.60, 0.2376], [4.80, 0.2326], [5.00, 0.2280]
+]);
+
+// G6: flat base, 6 cal secant ogive (spitzer military)
+export const G6_TABLE: DragTable = fromMach([
+  [0.00, 0.2617], [0.05, 0.2553], [0.10, 0.2491], [0.15, 0.2432], [0.20, 0.2376], [0.25, 0.2324], [0.30, 0.2278], [0.35, 0.2238],
+  [0.40, 0.2205], [0.45, 0.2177], [0.50, 0.2155], [0.55, 0.2138], [0.60, 0.2126], [0.65, 0.2121], [0.70, 0.2122], [0.75, 0.2132],
+  [0.80, 0.2154], [0.85, 0.2194], [0.875, 0.2229], [0.90, 0.2297], [0.925, 0.2449], [0.95, 0.2732], [0.975, 0.3141], [1.0, 0.3597],
+  [1.025, 0.3994], [1.05, 0.4261], [1.075, 0.4402], [1.10, 0.4465], [1.125, 0.4490], [1.15, 0.4497], [1.175, 0.4494], [1.20, 0.4482],
+  [1.225, 0.4464], [1.25, 0.4441], [1.30, 0.4390], [1.35, 0.4336], [1.40, 0.4279], [1.45, 0.4221], [1.50, 0.4162], [1.55, 0.4102],
+  [1.60, 0.4042], [1.65, 0.3981], [1.70, 0.3919], [1.75, 0.3855], [1.80, 0.3788], [1.85, 0.3721], [1.90, 0.3652], [1.95, 0.3583],
+  [2.00, 0.3515], [2.05, 0.3447], [2.10, 0.3381], [2.15, 0.3314], [2.20, 0.3249], [2.25, 0.3185], [2.30, 0.3122], [2.35, 0.3060],
+  [2.40, 0.3000], [2.45, 0.2941], [2.50, 0.2883], [2.60, 0.2772], [2.70, 0.2668], [2.80, 0.2574], [2.90, 0.2487], [3.00, 0.2407],
+  [3.10, 0.2333], [3.20, 0.2265], [3.30, 0.2202], [3.40, 0.2144], [3.50, 0.2089], [3.60, 0.2039], [3.70, 0.1991], [3.80, 0.1947],
+  [3.90, 0.1905], [4.00, 0.1866], [4.20, 0.1794], [4.40, 0.1730], [4.60, 0.1673], [4.80, 0.1621], [5.00, 0.1574]
+]);
+
+// G8: flat base, 10 cal secant ogive
+export const G8_TABLE: DragTable = fromMach([
+  [0.00, 0.2105], [0.05, 0.2105], [0.10, 0.2104], [0.15, 0.2104], [0.20, 0.2103], [0.25, 0.2103], [0.30, 0.2103], [0.35, 0.2103],
+  [0.40, 0.2103], [0.45, 0.2102], [0.50, 0.2102], [0.55, 0.2102], [0.60, 0.2102], [0.65, 0.2102], [0.70, 0.2103], [0.75, 0.2103],
+  [0.80, 0.2104], [0.825, 0.2104], [0.85, 0.2105], [0.875, 0.2106], [0.90, 0.2109], [0.925, 0.2183], [0.95, 0.2571], [0.975, 0.3358],
+  [1.0, 0.4068], [1.025, 0.4378], [1.05, 0.4476], [1.075, 0.4493], [1.10, 0.4477], [1.125, 0.4450], [1.15, 0.4419], [1.20, 0.4353],
+  [1.25, 0.4283], [1.30, 0.4208], [1.35, 0.4133], [1.40, 0.4059], [1.45, 0.3986], [1.50, 0.3915], [1.55, 0.3845], [1.60, 0.3777],
+  [1.65, 0.3710], [1.70, 0.3645], [1.75, 0.3581], [1.80, 0.3519], [1.85, 0.3458], [1.90, 0.3400], [1.95, 0.3343], [2.00, 0.3288],
+  [2.05, 0.3234], [2.10, 0.3182], [2.15, 0.3131], [2.20, 0.3081], [2.25, 0.3032], [2.30, 0.2983], [2.35, 0.2937], [2.40, 0.2891],
+  [2.45, 0.2845], [2.50, 0.2802], [2.60, 0.2720], [2.70, 0.2642], [2.80, 0.2569], [2.90, 0.2499], [3.00, 0.2432], [3.10, 0.2368],
+  [3.20, 0.2308], [3.30, 0.2251], [3.40, 0.2197], [3.50, 0.2147], [3.60, 0.2101], [3.70, 0.2058], [3.80, 0.2019], [3.90, 0.1983],
+  [4.00, 0.1950], [4.20, 0.1890], [4.40, 0.1837], [4.60, 0.1791], [4.80, 0.1750], [5.00, 0.1713]
+]);
+
+// GS: sphere (round ball)
+export const GS_TABLE: DragTable = fromMach([
+  [0.00, 0.4662], [0.05, 0.4689], [0.10, 0.4717], [0.15, 0.4745], [0.20, 0.4772], [0.25, 0.4800], [0.30, 0.4827], [0.35, 0.4852],
+  [0.40, 0.4882], [0.45, 0.4920], [0.50, 0.4970], [0.55, 0.5080], [0.60, 0.5260], [0.65, 0.5590], [0.70, 0.5920], [0.75, 0.6258],
+  [0.80, 0.6610], [0.85, 0.6985], [0.90, 0.7370], [0.95, 0.7757], [1.0, 0.8140], [1.05, 0.8512], [1.10, 0.8870], [1.15, 0.9210],
+  [1.20, 0.9510], [1.25, 0.9740], [1.30, 0.9910], [1.35, 0.9990], [1.40, 1.0030], [1.45, 1.0060], [1.50, 1.0080], [1.55, 1.0090],
+  [1.60, 1.0090], [1.65, 1.0090], [1.70, 1.0090], [1.75, 1.0080], [1.80, 1.0070], [1.85, 1.0060], [1.90, 1.0040], [1.95, 1.0025],
+  [2.00, 1.0010], [2.05, 0.9990], [2.10, 0.9970], [2.15, 0.9956], [2.20, 0.9940], [2.25, 0.9916], [2.30, 0.9890], [2.35, 0.9869],
+  [2.40, 0.9850], [2.45, 0.9830], [2.50, 0.9810], [2.60, 0.9770], [2.70, 0.9730], [2.80, 0.9690], [2.90, 0.9650], [3.00, 0.9610],
+  [3.10, 0.9570], [3.20, 0.9540], [3.30, 0.9510], [3.40, 0.9485], [3.50, 0.9460], [3.60, 0.9436], [3.70, 0.9412], [3.80, 0.9388],
+  [3.90, 0.9364], [4.00, 0.9340], [4.20, 0.9292], [4.40, 0.9244], [4.60, 0.9196], [4.80, 0.9148], [5.00, 0.9100]
+]);
 
 function interp(table: DragTable, v: number): number {
   if (v <= table[0].v) return table[0].f;
@@ -66,3 +137,137 @@ function interp(table: DragTable, v: number): number {
 
 export const fG1 = (v: number) => interp(G1_TABLE, v);
 export const fG7 = (v: number) => interp(G7_TABLE, v);
+export const fG2 = (v: number) => interp(G2_TABLE, v);
+export const fG5 = (v: number) => interp(G5_TABLE, v);
+export const fG6 = (v: number) => interp(G6_TABLE, v);
+export const fG8 = (v: number) => interp(G8_TABLE, v);
+export const fGS = (v: number) => interp(GS_TABLE, v);
+
+/* Smooth lookup: a monotone cubic (Fritsch–Carlson / PCHIP) through ln f.
+   Unlike the linear form its slope is continuous at the table points, so
+   sensitivities have no kinks and adaptive steps aren't cut at every
+   breakpoint; it never overshoots the data and f stays positive. */
+const smoothSlopes = new WeakMap<DragTable, number[]>();
+
+function slopesFor(table: DragTable): number[] {
+  const cached = smoothSlopes.get(table);
+  if (cached) return cached;
+  const n = table.length;
+  const y = table.map((p) => Math.log(p.f));
+  const h = table.slice(1).map((p, i) => p.v - table[i].v);
+  const d = h.map((hi, i) => (y[i + 1] - y[i]) / hi);
+  const m = y.map((_, k) => {
+    if (k === 0) return d[0] ?? 0;
+    if (k === n - 1) return d[n - 2];
+    if (d[k - 1] * d[k] <= 0) return 0;
+    const w1 = 2 * h[k] + h[k - 1], w2 = h[k] + 2 * h[k - 1];
+    return (w1 + w2) / (w1 / d[k - 1] + w2 / d[k]);
+  });
+  smoothSlopes.set(table, m);
+  return m;
+}
+
+export function interpSmooth(table: DragTable, v: number): number {
+  if (v <= table[0].v) return table[0].f;
+  const last = table[table.length - 1];
+  if (v >= last.v) return last.f;
+  const m = slopesFor(table);
+  let lo = 0, hi = table.length - 1;
+  while (hi - lo > 1) {
+    const mid = (lo + hi) >> 1;
+    if (table[mid].v <= v) lo = mid; else hi = mid;
+  }
+  const a = table[lo], b = table[hi];
+  const h = b.v - a.v;
+  const t = (v - a.v) / h;
+  const t2 = t * t, t3 = t2 * t;
+  const lnF = (2 * t3 - 3 * t2 + 1) * Math.log(a.f) + (t3 - 2 * t2 + t) * h * m[lo]
+    + (-2 * t3 + 3 * t2) * Math.log(b.f) + (t3 - t2) * h * m[hi];
+  return Math.exp(lnF);
+}
+
+export const STANDARD_TABLES: Record<string, DragTable | undefined> = {
+  G1: G1_TABLE, G2: G2_TABLE, G5: G5_TABLE, G6: G6_TABLE, G7: G7_TABLE, G8: G8_TABLE, GS: GS_TABLE,
+};
+
+/**
+ * Reference drag coefficient of a standard model at a Mach number. Every
+ * table is built from its published Cd(Mach) listing at the standard speed
+ * of sound (A_STD), so values from different families at the same Mach can
+ * be compared directly (convertBc relies on this). "noDrag" (or an unknown
+ * model) gives a vanishing value that still divides safely.
+ */
+export function dragAtMach(model: string, mach: number, smooth = false): number {
+  const table = STANDARD_TABLES[model];
+  if (!table) return 1e-12;
+  return smooth ? interpSmooth(table, mach * A_STD) : interp(table, mach * A_STD);
+}
+
+/** A BC converted between drag families, with the spread over the band */
+export type BcConversion = {
+  bc: number;           // band average
+  bcLow: number;        // smallest single-speed conversion in the band
+  bcHigh: number;       // largest
+  spread: number;       // (bcHigh - bcLow) / bc: how far off a fixed BC can be
+};
+
+/**
+ * Convert a BC from one standard family to another over a velocity band.
+ * A bullet's form factor against model X is i = Cd_bullet / Cd_X and its
+ * BC is SD / i, so BC_to = BC_from · Cd_to(M) / Cd_from(M). The ratio
+ * changes with Mach (most through the transonic), so it is averaged over
+ * the band and the extremes are reported as error bounds; a wide spread
+ * means no single BC in the new family will fit the whole flight.
+ */
+export function convertBc(
+  bc: number,
+  from: string,
+  to: string,
+  band: { minMps: number; maxMps: number }
+): BcConversion {
+  const lo = Math.min(band.minMps, band.maxMps) / A_STD;
+  const hi = Math.max(band.minMps, band.maxMps) / A_STD;
+  const n = 50;
+  const bcs: number[] = [];
+  for (let k = 0; k <= n; k++) {
+    const mach = lo + ((hi - lo) * k) / n;
+    bcs.push((bc * dragAtMach(to, mach)) / dragAtMach(from, mach));
+  }
+  const mean = bcs.reduce((a, b) => a + b, 0) / bcs.length;
+  const bcLow = Math.min(...bcs), bcHigh = Math.max(...bcs);
+  return { bc: mean, bcLow, bcHigh, spread: mean > 0 ? (bcHigh - bcLow) / mean : 0 };
+}
+
+/** The BC to keep when a load's drag model is switched: the same bullet
+ *  converted over the supersonic part of the flight (450 m/s up to the
+ *  muzzle velocity), rounded to 0.001. Unchanged if either side is noDrag. */
+export function bcForModelChange(bc: number, from: string, to: string, muzzleMps = 800): number {
+  if (from === to || !STANDARD_TABLES[from] || !STANDARD_TABLES[to] || !(bc > 0)) return bc;
+  const { bc: converted } = convertBc(bc, from, to, { minMps: 450, maxMps: muzzleMps || 800 });
+  return Math.round(converted * 1000) / 1000;
+}
+
+/* Custom drag curves (e.g. Doppler radar) are given as Cd vs Mach */
+export type CdPoint = { mach: number; cd: number };
+
+// converted once per table, not on every integrator step
+const cdTables = new WeakMap<CdPoint[], DragTable>();
+
+export function interpCd(table: CdPoint[], mach: number, smooth = false): number {
+  let t = cdTables.get(table);
+  if (!t) cdTables.set(table, (t = table.map((p) => ({ v: p.mach, f: p.cd }))));
+  return smooth ? interpSmooth(t, mach) : interp(t, mach);
+}
+
+/* Drag scale factors (DSF): a per-Mach multiplier on top of the drag model,
+   usually produced by truing against observed drops */
+export type DsfPoint = { mach: number; factor: number };
+
+const dsfTables = new WeakMap<DsfPoint[], DragTable>();
+
+export function interpDsf(table: DsfPoint[], mach: number): number {
+  if (!table.length) return 1;
+  let t = dsfTables.get(table);
+  if (!t) dsfTables.set(table, (t = table.map((p) => ({ v: p.mach, f: p.factor }))));
+  return interp(t, mach);
+}
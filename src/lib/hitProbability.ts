@@ -0,0 +1,101 @@
+// src/lib/hitProbability.ts
+import type { AmmoProfile, Environment } from "./appState";
+import { buildDopeTable, solveZeroAngle } from "./calcEngine";
+import type { SolveOptions } from "./calcEngine";
+
+/** One-sigma input uncertainties for a weapon-employment-zone estimate */
+export type Uncertainty = {
+  mvSdMps: number;        // shot-to-shot muzzle velocity SD
+  bcSdFraction: number;   // BC (or Cd curve) uncertainty, as a fraction (0.02 = 2 %)
+  windSdMps: number;      // error in the crosswind call
+  rangeSdM: number;       // ranging error
+};
+
+export type HitProbabilityRow = {
+  rangeM: number;
+  probability: number;    // 0..1
+  verticalSdM: number;    // spread of impacts about the aim point
+  lateralSdM: number;
+};
+
+/** Small seeded PRNG (mulberry32) so a given seed reproduces the same card */
+function rng(seed: number) {
+  let a = seed >>> 0;
+  return () => {
+    a = (a + 0x6d2b79f5) >>> 0;
+    let t = a;
+    t = Math.imul(t ^ (t >>> 15), t | 1);
+    t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
+    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+  };
+}
+
+/** Standard normal via Box–Muller */
+function gaussian(next: () => number) {
+  const u = Math.max(1e-12, next());
+  return Math.sqrt(-2 * Math.log(u)) * Math.cos(2 * Math.PI * next());
+}
+
+/**
+ * Monte Carlo hit probability on a width × height rectangle centred on the
+ * aim point. The shooter dials the nominal solution (called range, called
+ * wind); each sample draws the true MV, BC, wind and range and fires the
+ * perturbed bullet from the same bore (nominal zero angle + nominal hold).
+ */
+export function hitProbability(
+  ammo: AmmoProfile,
+  env: Environment,
+  rangesM: number[],
+  targetWidthM: number,
+  targetHeightM: number,
+  sigma: Uncertainty,
+  windSpeed = 0,
+  windAngleDeg = 90,
+  opts: SolveOptions = {},
+  samples = 500,
+  seed = 1
+): HitProbabilityRow[] {
+  const next = rng(seed);
+  // the rifle stays zeroed for the nominal load, whatever each shot does
+  const zeroAngleRad = opts.zeroAngleRad ?? solveZeroAngle(ammo, env);
+  const nominal = buildDopeTable(ammo, env, rangesM, windSpeed, windAngleDeg, { ...opts, zeroAngleRad });
+
+  const hits = rangesM.map(() => 0);
+  const dy: number[][] = rangesM.map(() => []);
+  const dz: number[][] = rangesM.map(() => []);
+
+  for (let k = 0; k < samples; k++) {
+    const bcScale = 1 + sigma.bcSdFraction * gaussian(next);
+    const shot: AmmoProfile = {
+      ...ammo,
+      V0: ammo.V0 + sigma.mvSdMps * gaussian(next),
+      bc: ammo.bc * bcScale,
+      // a measured Cd curve ignores the BC; drag goes as 1/BC, so scale Cd
+      cdTable: ammo.cdTable?.map((p) => ({ mach: p.mach, cd: p.cd / bcScale })),
+    };
+    const windErr = sigma.windSdMps * gaussian(next);
+    const trueRanges = rangesM.map((r) => Math.max(1, r + sigma.rangeSdM * gaussian(next)));
+    const needed = buildDopeTable(shot, env, trueRanges, windSpeed, windAngleDeg, { ...opts, zeroAngleRad });
+
+    needed.forEach((row, i) => {
+      const R = trueRanges[i];
+      const y = ((nominal[i].holdMil - row.holdMil) / 1000) * R; // + = high
+      // drift is linear in crosswind, so the call error adds driftPerMps·error
+      const z = ((row.lateralHoldMil - nominal[i].lateralHoldMil) / 1000) * R + row.driftPerMps * windErr; // + = right
+      dy[i].push(y);
+      dz[i].push(z);
+      if (Math.abs(y) <= targetHeightM / 2 && Math.abs(z) <= targetWidthM / 2) hits[i]++;
+    });
+  }
+
+  const sd = (xs: number[]) => {
+    const m = xs.reduce((s, x) => s + x, 0) / xs.length;
+    return Math.sqrt(xs.reduce((s, x) => s + (x - m) ** 2, 0) / Math.max(1, xs.length - 1));
+  };
+  return rangesM.map((rangeM, i) => ({
+    rangeM,
+    probability: hits[i] / samples,
+    verticalSdM: sd(dy[i]),
+    lateralSdM: sd(dz[i]),
+  }));
+}
@@ -0,0 +1,97 @@
+// src/lib/massProperties.ts
+// Mass, CG and moments of inertia of a turned bullet from its outline,
+// integrated numerically as a stack of thin discs.
+
+import type { AmmoProfile } from "./appState";
+
+/** A point on the bullet outline: station from the nose tip and radius, mm */
+export type ProfilePoint = { xMm: number; rMm: number };
+
+/** Solid (monolithic) bullet, or a jacket of constant wall thickness around
+ *  a core of a different density */
+export type BulletMaterial = {
+  densityKgM3: number;          // solid bullet, or the jacket
+  coreDensityKgM3?: number;
+  jacketThicknessMm?: number;
+};
+
+export const DENSITY_KG_M3 = {
+  copper: 8960,
+  gildingMetal: 8860,           // 95/5 Cu/Zn jacket
+  brass: 8500,
+  lead: 11340,                  // antimony-hardened cores run ~10 900
+};
+
+export type MassProperties = {
+  massKg: number;
+  massGr: number;
+  cgFromNoseMm: number;
+  lengthMm: number;
+  diameterMm: number;           // largest outline diameter
+  ixKgM2: number;               // about the spin axis
+  iyKgM2: number;               // transverse, about the CG
+};
+
+const GR_PER_KG = 15432.358;
+const SLICES_PER_MM = 20;
+
+/**
+ * Mass properties of the solid of revolution described by `outline`
+ * (sorted by station; the radius is linear between points). Each disc of
+ * radius r and length dx adds dm = ρ π r² dx, dm r²/2 to Ix and
+ * dm (r²/4 + x²) to the transverse moment about the nose, which is moved
+ * to the CG at the end. A jacketed bullet is a jacket-density solid with
+ * the core's extra density added inside r − t.
+ */
+export function massProperties(outline: ProfilePoint[], material: BulletMaterial): MassProperties {
+  const pts = [...outline].sort((a, b) => a.xMm - b.xMm);
+  const { densityKgM3, coreDensityKgM3, jacketThicknessMm = 0 } = material;
+  const coreExtra = coreDensityKgM3 !== undefined && jacketThicknessMm > 0 ? coreDensityKgM3 - densityKgM3 : 0;
+
+  let m = 0, mx = 0, ix = 0, iyNose = 0;
+  const disc = (rM: number, xM: number, dxM: number, rho: number) => {
+    if (rM <= 0) return;
+    const dm = rho * Math.PI * rM * rM * dxM;
+    m += dm;
+    mx += dm * xM;
+    ix += (dm * rM * rM) / 2;
+    iyNose += dm * (rM * rM / 4 + xM * xM);
+  };
+
+  for (let i = 1; i < pts.length; i++) {
+    const a = pts[i - 1], b = pts[i];
+    const len = b.xMm - a.xMm;
+    if (len <= 0) continue;
+    const n = Math.max(1, Math.ceil(len * SLICES_PER_MM));
+    for (let k = 0; k < n; k++) {
+      const f = (k + 0.5) / n;           // midpoint of the slice
+      const xMm = a.xMm + f * len;
+      const rMm = a.rMm + f * (b.rMm - a.rMm);
+      disc(rMm / 1000, xMm / 1000, len / n / 1000, densityKgM3);
+      if (coreExtra) disc((rMm - jacketThicknessMm) / 1000, xMm / 1000, len / n / 1000, coreExtra);
+    }
+  }
+
+  const cg = m > 0 ? mx / m : 0;
+  return {
+    massKg: m,
+    massGr: m * GR_PER_KG,
+    cgFromNoseMm: cg * 1000,
+    lengthMm: pts.length ? pts[pts.length - 1].xMm - pts[0].xMm : 0,
+    diameterMm: 2 * Math.max(0, ...pts.map((p) => p.rMm)),
+    ixKgM2: ix,
+    iyKgM2: iyNose - m * cg * cg,
+  };
+}
+
+/** Copy the computed geometry and inertias onto a load. Weight is left to
+ *  the caller: the scale beats the drawing. */
+export function withMassProperties(ammo: AmmoProfile, props: MassProperties): AmmoProfile {
+  return {
+    ...ammo,
+    bulletLengthMm: props.lengthMm,
+    bulletDiameterMm: props.diameterMm,
+    ixKgM2: props.ixKgM2,
+    iyKgM2: props.iyKgM2,
+  };
+}
@@ -0,0 +1,59 @@
+// src/lib/serialize.ts
+// Persist and replay solve requests. SolveInputs is plain data except for
+// the onStep observer (dropped) and non-finite stop conditions, which JSON
+// would silently turn into null.
+
+import { createSolveInputs } from "./calcEngine";
+import type { SolveInputs, SolveResult, SolveRow } from "./calcEngine";
+
+const INPUTS_VERSION = 1;
+
+const encodeNumber = (_key: string, value: unknown) => {
+  if (typeof value === "number" && !Number.isFinite(value)) {
+    return Number.isNaN(value) ? "NaN" : value > 0 ? "Infinity" : "-Infinity";
+  }
+  return value;
+};
+
+const decodeNumber = (_key: string, value: unknown) => {
+  if (value === "Infinity") return Infinity;
+  if (value === "-Infinity") return -Infinity;
+  if (value === "NaN") return NaN;
+  return value;
+};
+
+/** JSON for a solve request; the onStep observer is not persisted */
+export function solveInputsToJSON(inputs: SolveInputs): string {
+  const { onStep: _observer, ...opts } = inputs.opts;
+  return JSON.stringify({ version: INPUTS_VERSION, ...inputs, opts }, encodeNumber);
+}
+
+/** Parse a request saved by solveInputsToJSON and re-validate it */
+export function solveInputsFromJSON(json: string): SolveResult<SolveInputs> {
+  let raw: any;
+  try {
+    raw = JSON.parse(json, decodeNumber);
+  } catch (e) {
+    return { ok: false, error: { kind: "InvalidRequest", message: `Not valid JSON: ${(e as Error).message}` } };
+  }
+  if (raw?.version !== INPUTS_VERSION || typeof raw.ammo !== "object") {
+    return { ok: false, error: { kind: "InvalidRequest", message: "Not a saved solve request" } };
+  }
+  return createSolveInputs({
+    ammo: raw.ammo,
+    env: raw.env,
+    rangesM: raw.rangesM,
+    windSpeed: raw.windSpeed,
+    windAngleDeg: raw.windAngleDeg,
+    opts: raw.opts,
+  });
+}
+
+/** JSON for solved rows (same number encoding as the inputs) */
+export function rowsToJSON(rows: SolveRow[]): string {
+  return JSON.stringify(rows, encodeNumber);
+}
+
+export function rowsFromJSON(json: string): SolveRow[] {
+  return JSON.parse(json, decodeNumber);
+}
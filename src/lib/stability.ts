@@ -0,0 +1,161 @@
+// src/lib/stability.ts
+// Gyroscopic stability (Miller twist rule), Litz spin drift and the
+// modified-point-mass yaw-of-repose drift.
+// Inputs are in the units shooters have on hand; conversions happen here.
+
+import type { AmmoProfile, Environment } from "./appState";
+
+const MM_PER_IN = 25.4;
+
+/**
+ * Miller gyroscopic stability factor Sg.
+ *   Sg = 30 m / (t² d³ l (1 + l²))
+ * with m in grains, d in inches, t = twist in calibers, l = length in calibers,
+ * then corrected for velocity and air (temperature/pressure).
+ * Returns null if bullet length or diameter are unknown.
+ */
+export function millerStability(
+  ammo: AmmoProfile,
+  twistRateIn: number,
+  env: Environment,
+  velocityMps = ammo.V0
+): number | null {
+  if (!ammo.bulletLengthMm || !ammo.bulletDiameterMm || !twistRateIn) return null;
+
+  const d = ammo.bulletDiameterMm / MM_PER_IN;       // in
+  const l = ammo.bulletLengthMm / ammo.bulletDiameterMm; // calibers
+  const t = Math.abs(twistRateIn) / d;               // calibers per turn
+  const m = ammo.bulletWeightGr;
+
+  const sgStd = (30 * m) / (t * t * d * d * d * l * (1 + l * l));
+
+  // velocity correction, referenced to 2800 fps
+  const fps = velocityMps * 3.28084;
+  const fv = Math.cbrt(fps / 2800);
+
+  // air correction, referenced to 59 °F and 29.92 inHg
+  const tempF = env.temperatureC * 9 / 5 + 32;
+  const inHg = env.pressurehPa * 0.0295300;
+  const fa = ((tempF + 460) / (59 + 460)) * (29.92 / inHg);
+
+  return sgStd * fv * fa;
+}
+
+/**
+ * Drag penalty for marginal stability: a bullet with 1.0 < Sg < 1.5 flies
+ * with enough yaw to lose BC. Linear fit to Litz's measurements (about 15 %
+ * BC loss at Sg = 1.0, none from 1.5 up). Returns the multiplier for BC.
+ * Below 1.0 the bullet is unstable and no BC can be trusted; the factor is
+ * held at its Sg = 1.0 value and callers should warn.
+ */
+export function stabilityBcFactor(sg: number): number {
+  if (sg >= 1.5) return 1;
+  return 1 - 0.3 * (1.5 - Math.max(1, sg));
+}
+
+/** Human-readable stability warning, or undefined when Sg ≥ 1.5 */
+export function stabilityWarning(sg: number): string | undefined {
+  if (sg < 1) return `unstable: Sg ${sg.toFixed(2)} < 1.0`;
+  if (sg < 1.5) return `marginal stability: Sg ${sg.toFixed(2)}, BC reduced ${((1 - stabilityBcFactor(sg)) * 100).toFixed(0)} %`;
+  return undefined;
+}
+
+/**
+ * Litz spin drift (m) after `tof` seconds.
+ *   SD[in] = 1.25 (Sg + 1.2) TOF^1.83
+ * Positive = right, for a right-hand twist (positive twistRateIn).
+ */
+export function spinDrift(sg: number, tof: number, twistRateIn: number): number {
+  const inches = 1.25 * (sg + 1.2) * Math.pow(Math.max(0, tof), 1.83);
+  return Math.sign(twistRateIn) * inches * 0.0254;
+}
+
+/**
+ * Rate of Litz spin drift (m/s) at time t, for integrating along the path
+ * when Sg changes in flight: d/dt of 1.25 (Sg + 1.2) t^1.83 inches.
+ */
+export function spinDriftRate(sg: number, t: number, twistRateIn: number): number {
+  const inchesPerS = 1.83 * 1.25 * (sg + 1.2) * Math.pow(Math.max(0, t), 0.83);
+  return Math.sign(twistRateIn) * inchesPerS * 0.0254;
+}
+
+// Spin damping (McCoy): dp/dt = ρ V S d² Clp p / (2 Ix). With S = π d²/4
+// the rate only depends on the air mass swept, ∫ρ ds. Ix defaults to 0.1 m d².
+const CLP = -0.006;            // roll-damping coefficient, typical rifle bullet
+const IX_COEFF = 0.1;          // Ix / (m d²)
+const IY_LEN_COEFF = 0.06;     // Iy length term / (m l²); solid cylinder is 1/12
+const CL_ALPHA = 2.5;          // lift-force slope, typical supersonic rifle bullet
+
+/**
+ * Axial (ix) and transverse (iy) moments of inertia, kg·m². Measured or
+ * computed values on the load win; otherwise Ix is the IX_COEFF rule above
+ * and Iy the solid-cylinder form with the length term cut for the ogive and
+ * boat tail (fit to massProperties() outlines of jacketed match bullets).
+ * Null without length and diameter.
+ */
+export function momentsOfInertia(ammo: AmmoProfile): { ix: number; iy: number } | null {
+  if (!ammo.bulletLengthMm || !ammo.bulletDiameterMm) return null;
+  const d = ammo.bulletDiameterMm / 1000;
+  const l = ammo.bulletLengthMm / 1000;
+  const m = ammo.bulletWeightGr * 6.479891e-5;
+  return {
+    ix: ammo.ixKgM2 ?? IX_COEFF * m * d * d,
+    iy: ammo.iyKgM2 ?? m * (d * d / 16 + IY_LEN_COEFF * l * l),
+  };
+}
+
+/**
+ * Spin left after the bullet has swept `rhoPathKgM2` = ∫ρ ds (kg/m²) of air,
+ * as a fraction of muzzle spin. Returns 1 if the diameter is unknown.
+ */
+export function spinDecayRatio(ammo: AmmoProfile, rhoPathKgM2: number): number {
+  if (!ammo.bulletDiameterMm) return 1;
+  const d = ammo.bulletDiameterMm / 1000;
+  const m = ammo.bulletWeightGr * 6.479891e-5;
+  const ix = ammo.ixKgM2 ?? IX_COEFF * m * d * d;
+  const k = (Math.PI * d * d * d * d * CLP) / (8 * ix);
+  return Math.exp(k * rhoPathKgM2);
+}
+
+/**
+ * Modified point mass (McCoy / STANAG 4355): gravity curving the path makes
+ * a spinning bullet fly with a yaw of repose
+ *   α_R = 2 Ix p g⊥ / (ρ S d V³ Cmα),
+ * whose lift pulls it sideways at a = ρ S V² CLα α_R / (2 m). Cmα comes
+ * from the muzzle Sg (Sg = Ix² p² / (2 ρ S d Iy V² Cmα)), which leaves
+ *   a = K · (p / p0) · g⊥ / V,   K = 2 ρ S Iy V0² Sg CLα / (m Ix p0).
+ * Returns K (m/s), signed with the twist (+ = drift right).
+ */
+export function reposeLiftGain(
+  ammo: AmmoProfile,
+  sg: number,
+  rhoKgM3: number,
+  muzzleVelocityMps: number,
+  twistRateIn: number
+): number {
+  const inertia = momentsOfInertia(ammo);
+  if (!inertia || !twistRateIn) return 0;
+  const d = (ammo.bulletDiameterMm ?? 0) / 1000;
+  const S = (Math.PI * d * d) / 4;
+  const m = ammo.bulletWeightGr * 6.479891e-5;
+  const p0 = (2 * Math.PI * muzzleVelocityMps) / (Math.abs(twistRateIn) * MM_PER_IN / 1000);
+  const K = (2 * rhoKgM3 * S * inertia.iy * muzzleVelocityMps ** 2 * sg * CL_ALPHA) / (m * inertia.ix * p0);
+  return Math.sign(twistRateIn) * K;
+}
+
+/**
+ * Sg downrange: Miller evaluated at the local speed with the twist the
+ * bullet would need to spin at its current (decayed) rate,
+ *   twist_eq = twist · (v / V0) / spinRatio.
+ */
+export function downrangeStability(
+  ammo: AmmoProfile,
+  twistRateIn: number,
+  env: Environment,
+  muzzleVelocityMps: number,
+  velocityMps: number,
+  spinRatio: number
+): number | null {
+  const twistEq = (twistRateIn * (velocityMps / muzzleVelocityMps)) / Math.max(1e-6, spinRatio);
+  return millerStability(ammo, twistEq, env, velocityMps);
+}
@@ -0,0 +1,96 @@
+// src/lib/sweeps.ts
+import type { AmmoProfile, Environment } from "./appState";
+import { buildDopeTable, densityAltitude, turretClicks } from "./calcEngine";
+import type { SolveOptions } from "./calcEngine";
+
+export type SweepParam = "temperatureC" | "pressurehPa" | "humidityPct";
+
+export type SweepRow = {
+  value: number;              // swept parameter value
+  densityAltitudeM: number;
+  holdMil: number[];          // per requested range
+  deltaMil: number[];         // change vs. the base environment
+};
+
+/**
+ * Environmental sensitivity sweep ("DA card").
+ * Varies one environment parameter across `values`, keeps everything else at
+ * `baseEnv`, and reports the elevation hold at each range plus its change
+ * relative to the base environment. The zero is re-solved for every row, so
+ * the stored zeroEnv on the ammo profile is respected.
+ */
+export function environmentSweep(
+  ammo: AmmoProfile,
+  baseEnv: Environment,
+  param: SweepParam,
+  values: number[],
+  rangesM: number[]
+) {
+  const baseHolds = buildDopeTable(ammo, baseEnv, rangesM).map((r) => r.holdMil);
+
+  const rows: SweepRow[] = values.map((value) => {
+    const env: Environment = { ...baseEnv, [param]: value };
+    const holdMil = buildDopeTable(ammo, env, rangesM).map((r) => r.holdMil);
+    return {
+      value,
+      densityAltitudeM: densityAltitude(env),
+      holdMil,
+      deltaMil: holdMil.map((h, i) => h - baseHolds[i]),
+    };
+  });
+
+  return { param, rangesM, baseHoldMil: baseHolds, rows };
+}
+
+export type WindBracketRow = {
+  rangeM: number;
+  holdMil: number;
+  lateralHoldMil: number[];   // per requested wind speed
+  windageClicks?: number[];   // when opts.scope is given
+};
+
+/**
+ * Wind bracket card: lateral holds at each range for several wind speeds,
+ * from one no-wind table (one zero solve, one integration per range) plus
+ * the per-m/s drift sensitivity. Crosswind drift is linear in the
+ * crosswind, so the columns are exact when cant and vertical wind are off;
+ * with either on, wind also moves the vertical and the card is an
+ * approximation. A segmented wind profile has no single speed to bracket,
+ * so opts.windSegments is rejected with a TypeError.
+ */
+export function windBracket(
+  ammo: AmmoProfile,
+  env: Environment,
+  rangesM: number[],
+  windSpeeds: number[],
+  windAngleDeg = 90,
+  opts: SolveOptions = {}
+): WindBracketRow[] {
+  if (opts.windSegments?.length) {
+    throw new TypeError("windBracket brackets one uniform wind; solve windSegments with buildDopeTable");
+  }
+  const cross = Math.sin((windAngleDeg * Math.PI) / 180);
+  const base = buildDopeTable(ammo, env, rangesM, 0, windAngleDeg, opts);
+  return base.map((r, i) => {
+    const rangeM = rangesM[i];
+    const lateralHoldMil = windSpeeds.map(
+      (w) => r.lateralHoldMil + ((r.driftPerMps * w * cross) / rangeM) * 1000
+    );
+    return {
+      rangeM,
+      holdMil: r.holdMil,
+      lateralHoldMil,
+      windageClicks: opts.scope
+        ? lateralHoldMil.map((l) => turretClicks(r.holdMil, l, opts.scope!).windageClicks)
+        : undefined,
+    };
+  });
+}
+
+/** Evenly spaced values from `from` to `to` (inclusive) */
+export function sweepValues(from: number, to: number, step: number): number[] {
+  const out: number[] = [];
+  if (step <= 0) return [from];
+  for (let v = from; v <= to + 1e-9; v += step) out.push(Math.round(v * 1000) / 1000);
+  return out;
+}
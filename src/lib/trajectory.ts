@@ -0,0 +1,95 @@
+// src/lib/trajectory.ts
+// Common container for a full flight path, independent of the solver that
+// produced it. Coordinates follow calcEngine: x along the LOS (m), y above
+// the LOS (m), z right of the LOS (m).
+
+export type TrajectorySample = {
+  t: number;     // s since muzzle exit
+  x: number;     // m downrange
+  y: number;     // m above LOS
+  z: number;     // m right of LOS
+  v: number;     // m/s
+  mach: number;
+  /** velocity components (m/s), when the solver provides them; enables
+   *  cubic Hermite interpolation between samples */
+  vx?: number;
+  vy?: number;
+  vz?: number;
+};
+
+export type TrajectoryEvent = {
+  kind: string;  // e.g. "mach1.2", "mach1.0", "apex"
+  t: number;
+  x: number;
+  v: number;
+};
+
+export type Trajectory = {
+  samples: TrajectorySample[];
+  events: TrajectoryEvent[];
+};
+
+const lerp = (a: number, b: number, u: number) => a + (b - a) * u;
+
+/** Cubic Hermite on [0, 1]: end values p0/p1 and end slopes m0/m1 (already
+ *  scaled by the interval length). Returns value and d/du. */
+function hermite(p0: number, p1: number, m0: number, m1: number, u: number) {
+  const u2 = u * u, u3 = u2 * u;
+  return {
+    f: (2 * u3 - 3 * u2 + 1) * p0 + (u3 - 2 * u2 + u) * m0 + (-2 * u3 + 3 * u2) * p1 + (u3 - u2) * m1,
+    df: (6 * u2 - 6 * u) * p0 + (3 * u2 - 4 * u + 1) * m0 + (-6 * u2 + 6 * u) * p1 + (3 * u2 - 2 * u) * m1,
+  };
+}
+
+const hasVelocity = (p: TrajectorySample) => p.vx !== undefined && p.vy !== undefined && p.vz !== undefined;
+
+/** Interpolation of the path at downrange distance x (m): cubic Hermite in
+ *  x using the stored velocities (dy/dx = vy/vx, dt/dx = 1/vx), so large
+ *  integrator steps don't cost accuracy; linear if velocities are missing.
+ *  Speed and Mach are always linear. Returns null if x lies outside the
+ *  integrated path. */
+export function sampleAtRange(traj: Trajectory, x: number): TrajectorySample | null {
+  const s = traj.samples;
+  if (!s.length || x < s[0].x || x > s[s.length - 1].x) return null;
+  let lo = 0;
+  let hi = s.length - 1;
+  while (hi - lo > 1) {
+    const mid = (lo + hi) >> 1;
+    if (s[mid].x <= x) lo = mid;
+    else hi = mid;
+  }
+  const a = s[lo], b = s[hi];
+  const h = b.x - a.x;
+  const u = h > 0 ? (x - a.x) / h : 0;
+  const v = lerp(a.v, b.v, u);
+  const mach = lerp(a.mach, b.mach, u);
+  if (!hasVelocity(a) || !hasVelocity(b) || !(a.vx! > 0 && b.vx! > 0) || h <= 0) {
+    return { t: lerp(a.t, b.t, u), x, y: lerp(a.y, b.y, u), z: lerp(a.z, b.z, u), v, mach };
+  }
+  const t = hermite(a.t, b.t, h / a.vx!, h / b.vx!, u).f;
+  const y = hermite(a.y, b.y, (h * a.vy!) / a.vx!, (h * b.vy!) / b.vx!, u);
+  const z = hermite(a.z, b.z, (h * a.vz!) / a.vx!, (h * b.vz!) / b.vx!, u);
+  const vx = lerp(a.vx!, b.vx!, u);
+  return { t, x, y: y.f, z: z.f, v, mach, vx, vy: (y.df / h) * vx, vz: (z.df / h) * vx };
+}
+
+/** Same as sampleAtRange but indexed by time of flight (s) */
+export function sampleAtTime(traj: Trajectory, t: number): TrajectorySample | null {
+  const s = traj.samples;
+  if (!s.length || t < s[0].t || t > s[s.length - 1].t) return null;
+  const i = Math.max(1, s.findIndex((p) => p.t >= t));
+  const a = s[i - 1], b = s[i];
+  const h = b.t - a.t;
+  const u = h > 0 ? (t - a.t) / h : 0;
+  const v = lerp(a.v, b.v, u);
+  const mach = lerp(a.mach, b.mach, u);
+  if (!hasVelocity(a) || !hasVelocity(b) || h <= 0) {
+    return { t, x: lerp(a.x, b.x, u), y: lerp(a.y, b.y, u), z: lerp(a.z, b.z, u), v, mach };
+  }
+  const x = hermite(a.x, b.x, h * a.vx!, h * b.vx!, u);
+  const y = hermite(a.y, b.y, h * a.vy!, h * b.vy!, u);
+  const z = hermite(a.z, b.z, h * a.vz!, h * b.vz!, u);
+  return { t, x: x.f, y: y.f, z: z.f, v, mach, vx: x.df / h, vy: y.df / h, vz: z.df / h };
+}
+
+export { trajectoryToCSV } from "./csvExport";
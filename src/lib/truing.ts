@@ -0,0 +1,188 @@
+// src/lib/truing.ts
+// Ballistic truing: adjust one model input until the solver reproduces a hold
+// observed in the field. Holds are elevation MIL from the stored zero.
+
+import type { AmmoProfile, Environment } from "./appState";
+import { solveTrajectory, type SolveOptions } from "./calcEngine";
+import type { DsfPoint } from "./dragTables";
+
+export type TruingResult = {
+  value: number;             // trued BC / MV / …
+  predictedHoldMil: number;  // hold with the trued value
+  residualMil: number;       // predicted − observed
+  iterations: number;
+  converged: boolean;
+};
+
+/**
+ * Generic bisection on one scalar: `apply` builds the ammo for a candidate
+ * value, and the hold is assumed monotonic in that value over [lo, hi].
+ */
+function bisectHold(
+  apply: (value: number) => AmmoProfile,
+  env: Environment,
+  rangeM: number,
+  observedHoldMil: number,
+  lo: number,
+  hi: number,
+  opts: SolveOptions,
+  tolMil = 0.005
+): TruingResult {
+  const holdAt = (value: number) =>
+    solveTrajectory(apply(value), env, rangeM, 0, 90, 1.0, opts).holdMil;
+
+  let fLo = holdAt(lo) - observedHoldMil;
+  let mid = 0.5 * (lo + hi);
+  let fMid = Infinity;
+  let i = 0;
+  for (; i < 40; i++) {
+    mid = 0.5 * (lo + hi);
+    fMid = holdAt(mid) - observedHoldMil;
+    if (Math.abs(fMid) < tolMil) break;
+    if (fLo * fMid <= 0) {
+      hi = mid;
+    } else {
+      lo = mid;
+      fLo = fMid;
+    }
+  }
+  return {
+    value: mid,
+    predictedHoldMil: fMid + observedHoldMil,
+    residualMil: fMid,
+    iterations: i + 1,
+    converged: Math.abs(fMid) < tolMil,
+  };
+}
+
+/** Find the BC that makes the predicted hold at observedRangeM match the
+ *  observed hold. Best done far out, where drop is drag-dominated. A load
+ *  with a measured Cd curve ignores its BC, so there the curve is divided by
+ *  candidate / ammo.bc instead (ammo.bc taken as 1 if unset): the result's
+ *  value over that reference is the factor the trued curve needs. */
+export function trueBC(
+  ammo: AmmoProfile,
+  env: Environment,
+  observedRangeM: number,
+  observedHoldMil: number,
+  opts: SolveOptions = {}
+): TruingResult {
+  const ref = ammo.cdTable && !(ammo.bc > 0) ? 1 : ammo.bc;
+  return bisectHold(
+    (bc) => ({
+      ...ammo,
+      bc,
+      // drag goes as 1/BC, so scale Cd the other way (as hitProbability does)
+      cdTable: ammo.cdTable?.map((p) => ({ mach: p.mach, cd: (p.cd * ref) / bc })),
+    }),
+    env,
+    observedRangeM,
+    observedHoldMil,
+    ref * 0.3,
+    ref * 3,
+    opts
+  );
+}
+
+/** Find the muzzle velocity that reproduces the observed hold. Use a
+ *  mid-range observation, where drop is dominated by MV rather than drag.
+ *  Also reports how many MIL of hold 1 m/s of MV is worth at that range, so
+ *  the residual can be judged against chronograph error. */
+export function trueMV(
+  ammo: AmmoProfile,
+  env: Environment,
+  observedRangeM: number,
+  observedHoldMil: number,
+  opts: SolveOptions = {}
+): TruingResult & { milPerMps: number } {
+  const res = bisectHold(
+    (V0) => ({ ...ammo, V0 }),
+    env,
+    observedRangeM,
+    observedHoldMil,
+    ammo.V0 * 0.8,
+    ammo.V0 * 1.2,
+    opts
+  );
+  const holdAt = (V0: number) =>
+    solveTrajectory({ ...ammo, V0 }, env, observedRangeM, 0, 90, 1.0, opts).holdMil;
+  const milPerMps = (holdAt(res.value + 1) - holdAt(res.value - 1)) / 2;
+  return { ...res, milPerMps };
+}
+
+/**
+ * "True the wind" from a first-round miss. observedDriftMil is the total
+ * sideways deflection of the impact from the point of aim (+ = right), i.e.
+ * the miss plus any windage that was held or dialed. Everything else in the
+ * solution (spin drift, Coriolis, zero offset) is assumed trued, so what is
+ * left over is wind; drift is linear in crosswind, so this is exact.
+ * Returns the effective crosswind (+ = from the left, pushing right) and the
+ * matching wind speed for the given angle (null for a head/tail wind).
+ */
+export function inferWind(
+  ammo: AmmoProfile,
+  env: Environment,
+  rangeM: number,
+  observedDriftMil: number,
+  windAngleDeg = 90,
+  opts: SolveOptions = {}
+): { crosswindMps: number; windSpeedMps: number | null } {
+  const calm = solveTrajectory(ammo, env, rangeM, 0, 90, 1.0, opts);
+  const milPerMps = (calm.driftPerMps / rangeM) * 1000;
+  const crosswindMps = (observedDriftMil - calm.lateralHoldMil) / milPerMps;
+  const cross = Math.sin((windAngleDeg * Math.PI) / 180);
+  return { crosswindMps, windSpeedMps: Math.abs(cross) > 1e-3 ? crosswindMps / cross : null };
+}
+
+/**
+ * Drag-scale-factor truing curve. A single trued BC can't fix a drag model
+ * that is wrong in one Mach band (typically transonic), so each observed
+ * hold sets the DSF over the leg flown since the previous one. Points are
+ * trued one at a time with the others held, and the sweep is repeated
+ * (each point's Mach moves as the table changes) until all observations
+ * are matched. The returned table goes straight into ammo.dsfTable.
+ * Start from a trued MV.
+ */
+export function trueDSF(
+  ammo: AmmoProfile,
+  env: Environment,
+  observations: { rangeM: number; holdMil: number }[],
+  opts: SolveOptions = {},
+  maxSweeps = 6
+): { dsfTable: DsfPoint[]; points: TruingResult[] } {
+  const obs = [...observations].sort((a, b) => a.rangeM - b.rangeM);
+  const byMach = (t: DsfPoint[]) => [...t].sort((a, b) => a.mach - b.mach);
+  const withTable = (t: DsfPoint[]) => ({ ...ammo, dsfTable: byMach(t) });
+  const machAt = (t: DsfPoint[], rangeM: number) =>
+    solveTrajectory(withTable(t), env, rangeM, 0, 90, 1.0, opts).mach;
+
+  // a node at the observation's own Mach barely moves that observation's
+  // hold (late drag adds little drop), so each node sits at the Mach halfway
+  // through the leg flown since the previous observation
+  const legMid = obs.map((o, i) => 0.5 * ((i ? obs[i - 1].rangeM : 0) + o.rangeM));
+  let pts: DsfPoint[] = obs.map((_, i) => ({ mach: machAt([], legMid[i]), factor: 1 }));
+  let points: TruingResult[] = [];
+
+  for (let sweep = 0; sweep < maxSweeps; sweep++) {
+    points = obs.map((o, i) => {
+      const others = pts.filter((_, j) => j !== i);
+      const mach = machAt(pts, legMid[i]);
+      const res = bisectHold(
+        (factor) => withTable([...others, { mach, factor }]),
+        env,
+        o.rangeM,
+        o.holdMil,
+        0.5,
+        2,
+        opts
+      );
+      pts = pts.map((p, j) => (j === i ? { mach, factor: res.value } : p));
+      return res;
+    });
+    // later points moved the earlier ones' Mach band: re-check them all
+    const residuals = obs.map((o) => solveTrajectory(withTable(pts), env, o.rangeM, 0, 90, 1.0, opts).holdMil - o.holdMil);
+    points = points.map((p, i) => ({ ...p, residualMil: residuals[i], converged: Math.abs(residuals[i]) < 0.005 }));
+    if (points.every((p) => p.converged)) break;
+  }
+  return { dsfTable: byMach(pts), points };
+}
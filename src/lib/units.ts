@@ -0,0 +1,60 @@
+// src/lib/units.ts
+import type { SolveRow } from "./calcEngine";
+
+export const M_PER_YD = 0.9144;
+export const M_PER_FT = 0.3048;
+export const M_PER_IN = 0.0254;
+export const MPS_PER_MPH = 0.44704;
+export const FTLBF_PER_J = 0.7375621;
+
+export const yardsToMeters = (yd: number) => yd * M_PER_YD;
+export const metersToYards = (m: number) => m / M_PER_YD;
+export const mpsToFps = (mps: number) => mps / M_PER_FT;
+export const fpsToMps = (fps: number) => fps * M_PER_FT;
+export const metersToInches = (m: number) => m / M_PER_IN;
+
+/** A DOPE row in US customary units. Angular holds are unit-free and are
+ *  carried over unchanged. */
+export type ImperialRow = {
+  rangeYd: number;
+  tof: number;
+  impactVelFps: number;
+  mach: number;
+  energyFtLbf: number;
+  dropIn: number;
+  driftIn: number;
+  driftPerMphIn: number;     // inches per 1 mph full-value wind
+  spinDriftIn: number;
+  coriolisDriftIn: number;
+  holdMil: number;
+  holdMoa: number;
+  lateralHoldMil: number;
+  clicks: SolveRow["clicks"];
+  warning?: string;
+};
+
+/** Convert one solver row (computed at `rangeM`) to yards / fps / inches / ft·lbf */
+export function toImperial(row: SolveRow, rangeM: number): ImperialRow {
+  return {
+    rangeYd: metersToYards(rangeM),
+    tof: row.tof,
+    impactVelFps: mpsToFps(row.impactVel),
+    mach: row.mach,
+    energyFtLbf: row.energyFtLbf,
+    dropIn: metersToInches(row.dropM),
+    driftIn: metersToInches(row.driftM),
+    driftPerMphIn: metersToInches(row.driftPerMph),
+    spinDriftIn: metersToInches(row.spinDriftM),
+    coriolisDriftIn: metersToInches(row.coriolisDriftM),
+    holdMil: row.holdMil,
+    holdMoa: row.holdMoa,
+    lateralHoldMil: row.lateralHoldMil,
+    clicks: row.clicks,
+    warning: row.warning,
+  };
+}
+
+/** Convert a table built over `rangesM` */
+export function tableToImperial(rows: SolveRow[], rangesM: number[]): ImperialRow[] {
+  return rows.map((r, i) => toImperial(r, rangesM[i]));
+}
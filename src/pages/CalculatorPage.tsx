@@ -6,8 +6,11 @@ import { Input } from "../components/ui/input";
 import { Label } from "../components/ui/label";
 import { Button } from "../components/ui/button";
 import type { Environment } from "../lib/appState";
-import { buildDopeTable } from "../lib/calcEngine";
-import { computeCoriolisHold } from "../utils/coriolis";
+import {
+  buildDopeTable, clockFromWindAngle, environmentFromAltitude, environmentFromDensityAltitude,
+  supersonicSummary, windAngleFromClock,
+} from "../lib/calcEngine";
+import type { SolveOptions } from "../lib/calcEngine";
 import { fetchFMIWeather } from "../utils/fmi";
 import { toast } from "sonner@2.0.3";
 
@@ -34,9 +37,18 @@ export function CalculatorPage() {
     altitudeM: 0,
   });
 
+  // Density altitude straight from a weather meter (replaces pressure)
+  const [daInput, setDaInput] = useState(0);
+
   // Wind
   const [windSpeed, setWindSpeed] = useState(0);
-  const [windAngle, setWindAngle] = useState(90); // deg: 0=N, 90=E (left->right)
+  const [windAngle, setWindAngle] = useState(90); // deg: 0 = headwind, 90 = from the left (left->right)
+
+  // Look angle (uphill +, downhill −); ranges below are then slant ranges
+  const [lookAngle, setLookAngle] = useState(0);
+
+  // Rifle cant (+ clockwise); holds then include the cant-induced miss
+  const [cant, setCant] = useState(0);
 
   // Ranges
   const [rangesText, setRangesText] = useState("100,200,300,400,500,600");
@@ -108,57 +120,53 @@ export function CalculatorPage() {
     .map((s) => parseInt(s.trim(), 10))
     .filter((n) => Number.isFinite(n) && n > 0);
 
-  // Base ballistics (no Coriolis merged yet)
-  // buildDopeTable(ammo, env, ranges, windSpeed, windAngle)
-  // -> [{ tof, impactVel, dropM, holdMil, holdMoa, driftM }, ...]
-  const base = buildDopeTable(ammo, env, ranges, windSpeed, windAngle);
+  // Base ballistics; Coriolis/Eötvös are integrated by the solver when enabled
+  // buildDopeTable(ammo, env, ranges, windSpeed, windAngle, opts)
+  // -> [{ tof, impactVel, dropM, holdMil, holdMoa, driftM, spinDriftM, coriolisDriftM }, ...]
+  // Known latitude also selects the local-gravity model in the solver
+  const solveEnv: Environment = latitude !== null ? { ...env, latitudeDeg: latitude } : env;
+  const solveOpts: SolveOptions = {
+    twistRateIn: weapon?.twistRateIn,
+    azimuthDeg: useCoriolis ? azimuth : undefined,
+    lookAngleDeg: lookAngle,
+    cantDeg: cant,
+    scope: { units: scopeUnits, clickValue: scopeClick },
+  };
+  const base = buildDopeTable(ammo, solveEnv, ranges, windSpeed, windAngle, solveOpts);
+
+  // Where the bullet goes transonic (Mach 1.2) and subsonic (Mach 1.0),
+  // under the same conditions as the rows
+  const supersonic = supersonicSummary(ammo, solveEnv, undefined, windSpeed, windAngle, solveOpts);
 
-  // Merge Coriolis if enabled and latitude available
   const rows = base.map((row, i) => {
     const rangeM  = ranges[i];
-    const tof     = row.tof ?? 0;
-    const vImpact = row.impactVel ?? Math.max(0, ammo.V0 - 300);
-    const vMuzzle = ammo.V0;
-
-    let cor = { elevMil: 0, elevMoa: 0, windMil: 0, windMoa: 0, eastDriftM: 0, upShiftM: 0 };
-    if (useCoriolis && latitude !== null && Number.isFinite(tof) && tof > 0) {
-      cor = computeCoriolisHold({
-        rangeM,
-        tofSec: tof,
-        vMuzzle,
-        vImpact,
-        latitudeDeg: latitude,
-        azimuthDeg: azimuth,
-      });
-    }
 
-    // holds implicitly include Coriolis (no separate columns shown)
-    const holdMil = (row.holdMil ?? 0) + cor.elevMil;
-    const holdMoa = (row.holdMoa ?? 0) + cor.elevMoa;
+    // holds already include Eötvös (vertical Coriolis) from the solver
+    const holdMil = row.holdMil ?? 0;
+    const holdMoa = row.holdMoa ?? 0;
 
-    // convert Coriolis lateral mils to meters; add to ballistic drift
-    const corDriftM = (cor.windMil / 1000) * rangeM;
-    const driftM = (row.driftM ?? 0) + corDriftM;
+    // lateral: wind + spin + horizontal Coriolis
+    const driftM = (row.driftM ?? 0) + (row.spinDriftM ?? 0) + (row.coriolisDriftM ?? 0);
 
-    // Wind mils (incl. Coriolis) = total driftM / rangeM * 1000
-    const totalWindMil = (driftM / Math.max(rangeM, 1)) * 1000;
+    // Windage hold from the solver: wind, spin and Coriolis plus cant drift,
+    // zero offset and the zeroing-day wind, the same figure the clicks use
+    const windMil = row.lateralHoldMil ?? 0;
 
     // holds in selected unit
     const elevInUnits = scopeUnits === "MIL" ? holdMil : holdMoa;
-    const windInUnits = scopeUnits === "MIL" ? totalWindMil : totalWindMil * 3.437746; // mil→MOA
+    const windInUnits = scopeUnits === "MIL" ? windMil : windMil * 3.437746; // mil→MOA
 
-    // clicks (rounded to 0.1 click for nicer display)
-    const roundClicks = (u: number) =>
-      Math.round((Math.abs(u) / Math.max(scopeClick, 1e-9)) * 10) / 10;
-
-    const elevClicks = roundClicks(elevInUnits);
-    const windClicks = roundClicks(windInUnits);
+    // whole clicks from the solver, signed like the holds (+ = UP / LEFT)
+    const elevClicks = row.clicks?.elevationClicks ?? 0;
+    const windClicks = row.clicks?.windageClicks ?? 0;
 
     // Dial strings
+    const elevUp = elevInUnits >= 0;
+    const windLeft = windInUnits >= 0;
     const elevDial =
-      (elevInUnits >= 0 ? "UP " : "DOWN ") + `${elevClicks} clicks`;
+      (elevUp ? "UP " : "DOWN ") + `${elevUp ? elevClicks : -elevClicks} clicks`;
     const windDial =
-      (windInUnits >= 0 ? "LEFT " : "RIGHT ") + `${windClicks} clicks`;
+      (windLeft ? "LEFT " : "RIGHT ") + `${windLeft ? windClicks : -windClicks} clicks`;
 
     return {
       ...row,
@@ -220,18 +228,78 @@ export function CalculatorPage() {
             />
           </div>
           <div>
-            <Label>Wind Angle (°)</Label>
+            <Label>Wind Angle (° from target, 90 = from left)</Label>
             <Input
               type="number"
               value={windAngle}
               onChange={(e) => setWindAngle(Number(e.target.value))}
             />
           </div>
+          <div>
+            <Label>Wind From (o'clock)</Label>
+            <Input
+              type="number"
+              min={1}
+              max={12}
+              step={0.5}
+              value={Math.round(clockFromWindAngle(windAngle) * 10) / 10}
+              onChange={(e) => setWindAngle(windAngleFromClock(Number(e.target.value)))}
+            />
+          </div>
+
+          <div>
+            <Label>Look Angle (°, + uphill)</Label>
+            <Input
+              type="number"
+              value={lookAngle}
+              onChange={(e) => setLookAngle(Number(e.target.value))}
+            />
+          </div>
+
+          <div>
+            <Label>Cant (°, + clockwise)</Label>
+            <Input
+              type="number"
+              value={cant}
+              onChange={(e) => setCant(Number(e.target.value))}
+            />
+          </div>
 
           <div className="sm:col-span-2 flex flex-wrap gap-2 pt-1">
             <Button type="button" variant="outline" onClick={handleUseFMI}>
               Use FMI weather near me
             </Button>
+            <Button
+              type="button"
+              variant="outline"
+              onClick={() => {
+                const est = environmentFromAltitude(env.temperatureC, env.humidityPct, env.altitudeM ?? 0);
+                setEnv({ ...est, pressurehPa: Math.round(est.pressurehPa) });
+              }}
+            >
+              Estimate pressure from altitude
+            </Button>
+          </div>
+
+          <div className="sm:col-span-2 flex flex-wrap items-end gap-2">
+            <div>
+              <Label>Density Altitude (m)</Label>
+              <Input
+                type="number"
+                value={daInput}
+                onChange={(e) => setDaInput(Number(e.target.value))}
+              />
+            </div>
+            <Button
+              type="button"
+              variant="outline"
+              onClick={() => {
+                const est = environmentFromDensityAltitude(daInput, env.temperatureC, env.humidityPct);
+                setEnv({ ...env, pressurehPa: Math.round(est.pressurehPa * 10) / 10 });
+              }}
+            >
+              Use density altitude
+            </Button>
           </div>
         </CardContent>
       </Card>
@@ -300,20 +368,25 @@ export function CalculatorPage() {
                   <th className="px-2 py-1">Range (m)</th>
                   <th className="px-2 py-1">TOF (s)</th>
                   <th className="px-2 py-1">Impact Vel (m/s)</th>
+                  <th className="px-2 py-1">Mach</th>
+                  <th className="px-2 py-1">Energy (J)</th>
                   <th className="px-2 py-1">Drop (m)</th>
                   <th className="px-2 py-1">Elevation Hold ({scopeUnits})</th>
                   <th className="px-2 py-1">Suggested Elevation Dial</th>
                   <th className="px-2 py-1">Wind Hold ({scopeUnits})</th>
                   <th className="px-2 py-1">Suggested Wind Dial</th>
                   <th className="px-2 py-1">Wind Drift (m)</th>
+                  <th className="px-2 py-1">Drift / 1 m/s (cm)</th>
                 </tr>
               </thead>
               <tbody>
                 {rows.map((r, i) => (
-                  <tr key={i} className="border-t">
+                  <tr key={i} className={r.warning ? "border-t text-amber-700" : "border-t"} title={r.warning}>
                     <td className="px-2 py-1 text-center">{ranges[i]}</td>
                     <td className="px-2 py-1 text-center">{(r.tof ?? 0).toFixed(2)}</td>
                     <td className="px-2 py-1 text-center">{(r.impactVel ?? 0).toFixed(1)}</td>
+                    <td className="px-2 py-1 text-center">{(r.mach ?? 0).toFixed(2)}</td>
+                    <td className="px-2 py-1 text-center">{(r.energyJ ?? 0).toFixed(0)}</td>
                     <td className="px-2 py-1 text-center">{(r.dropM ?? 0).toFixed(2)}</td>
 
                     {/* Holds already include Coriolis (if enabled) */}
@@ -326,12 +399,26 @@ export function CalculatorPage() {
                     <td className="px-2 py-1 text-center">{r.windDial}</td>
 
                     <td className="px-2 py-1 text-center">{(r.driftM ?? 0).toFixed(2)}</td>
+                    <td className="px-2 py-1 text-center">{((r.driftPerMps ?? 0) * 100).toFixed(1)}</td>
                   </tr>
                 ))}
               </tbody>
             </table>
           </div>
 
+          <p className="text-xs text-muted-foreground mt-2">
+            Mach 1.2 at{" "}
+            {supersonic.rangeMach1_2M !== null ? `${supersonic.rangeMach1_2M.toFixed(0)} m` : "> 3000 m"}
+            {" • "}Subsonic at{" "}
+            {supersonic.rangeMach1_0M !== null ? `${supersonic.rangeMach1_0M.toFixed(0)} m` : "> 3000 m"}
+            {base[0]?.sg != null && (
+              <>
+                {" • "}Sg {base[0].sg.toFixed(2)}
+                {base[0].sg < 1.5 && " (marginal: BC reduced)"}
+              </>
+            )}
+          </p>
+
           {useCoriolis && latitude !== null && (
             <p className="text-xs text-muted-foreground mt-2">
               Holds above already include Coriolis when enabled.
@@ -1,6 +1,7 @@
 import React from "react";
 import { useApp } from "../contexts/AppContext";
-import type { Weapon, AmmoProfile } from "../lib/appState";
+import type { Weapon, AmmoProfile, ModelKind } from "../lib/appState";
+import { bcForModelChange } from "../lib/dragTables";
 import { Button } from "../components/ui/button";
 import { Input } from "../components/ui/input";
 import { Label } from "../components/ui/label";
@@ -323,14 +324,22 @@ export function EquipmentPage() {
                                   <Label>Drag Model</Label>
                                   <Select
                                     value={a.model}
-                                    onValueChange={(val) => patchAmmo(w.id, a.id, { model: val as any })}
+                                    onValueChange={(val: ModelKind) =>
+                                      // same bullet, new family: carry the BC across
+                                      patchAmmo(w.id, a.id, { model: val, bc: bcForModelChange(a.bc, a.model, val, a.V0) })
+                                    }
                                   >
                                     <SelectTrigger>
                                       <SelectValue />
                                     </SelectTrigger>
                                     <SelectContent>
                                       <SelectItem value="G1">G1</SelectItem>
+                                      <SelectItem value="G2">G2</SelectItem>
+                                      <SelectItem value="G5">G5</SelectItem>
+                                      <SelectItem value="G6">G6</SelectItem>
                                       <SelectItem value="G7">G7</SelectItem>
+                                      <SelectItem value="G8">G8</SelectItem>
+                                      <SelectItem value="GS">GS (sphere)</SelectItem>
                                       <SelectItem value="noDrag">No Drag</SelectItem>
                                     </SelectContent>
                                   </Select>
@@ -344,6 +353,35 @@ export function EquipmentPage() {
                                 />
                               </div>
 
+                              <div className="grid grid-cols-2 md:grid-cols-4 gap-3">
+                                <NumberInput
+                                  id={`bl-${a.id}`}
+                                  label="Bullet Length (mm)"
+                                  value={a.bulletLengthMm ?? 0}
+                                  onChange={(v) => patchAmmo(w.id, a.id, { bulletLengthMm: v || undefined })}
+                                />
+                                <NumberInput
+                                  id={`bd-${a.id}`}
+                                  label="Bullet Diameter (mm)"
+                                  value={a.bulletDiameterMm ?? 0}
+                                  step="0.01"
+                                  onChange={(v) => patchAmmo(w.id, a.id, { bulletDiameterMm: v || undefined })}
+                                />
+                                <NumberInput
+                                  id={`mvts-${a.id}`}
+                                  label="MV Temp Sens. (m/s per °C)"
+                                  value={a.mvTempSensitivity ?? 0}
+                                  step="0.01"
+                                  onChange={(v) => patchAmmo(w.id, a.id, { mvTempSensitivity: v || undefined })}
+                                />
+                                <NumberInput
+                                  id={`mvrt-${a.id}`}
+                                  label="MV Measured at (°C)"
+                                  value={a.mvReferenceTempC ?? a.zeroEnv.temperatureC}
+                                  onChange={(v) => patchAmmo(w.id, a.id, { mvReferenceTempC: v })}
+                                />
+                              </div>
+
                               <div className="grid grid-cols-2 md:grid-cols-4 gap-3">
                                 <NumberInput
                                   id={`zero-${a.id}`}
@@ -384,6 +422,33 @@ export function EquipmentPage() {
                                     patchAmmo(w.id, a.id, { zeroEnv: { ...a.zeroEnv, altitudeM: v } })
                                   }
                                 />
+                                <NumberInput
+                                  id={`zou-${a.id}`}
+                                  label="Zero POI High (cm)"
+                                  value={a.zeroOffsetUpCm ?? 0}
+                                  step="0.1"
+                                  onChange={(v) => patchAmmo(w.id, a.id, { zeroOffsetUpCm: v || undefined })}
+                                />
+                                <NumberInput
+                                  id={`zor-${a.id}`}
+                                  label="Zero POI Right (cm)"
+                                  value={a.zeroOffsetRightCm ?? 0}
+                                  step="0.1"
+                                  onChange={(v) => patchAmmo(w.id, a.id, { zeroOffsetRightCm: v || undefined })}
+                                />
+                                <NumberInput
+                                  id={`zws-${a.id}`}
+                                  label="Zero Wind (m/s)"
+                                  value={a.zeroWindSpeed ?? 0}
+                                  step="0.1"
+                                  onChange={(v) => patchAmmo(w.id, a.id, { zeroWindSpeed: v || undefined })}
+                                />
+                                <NumberInput
+                                  id={`zwa-${a.id}`}
+                                  label="Zero Wind Angle (°)"
+                                  value={a.zeroWindAngleDeg ?? 90}
+                                  onChange={(v) => patchAmmo(w.id, a.id, { zeroWindAngleDeg: v })}
+                                />
                               </div>
                             </CardContent>
                           )}
@@ -15,12 +15,16 @@ export type WeatherResult = {
 const RD = 287.058;   // J/(kg·K), dry air specific gas constant
 const RV = 461.495;   // J/(kg·K), water vapor specific gas constant
 
-// Calculate air density from atmospheric conditions using ideal gas law
-export function computeAirDensity(pressurePa: number, temperatureC: number, rhPercent: number): number {
+// Partial pressure of water vapor (Pa) from temperature and relative humidity
+export function vaporPressurePa(temperatureC: number, rhPercent: number): number {
   // Magnus-Tetens saturation vapor pressure over water (hPa)
   const es_hPa = 6.112 * Math.exp((17.62 * temperatureC) / (243.12 + temperatureC));
-  const e_hPa  = (Math.max(0, Math.min(100, rhPercent)) / 100) * es_hPa;
-  const e_Pa   = e_hPa * 100;
+  return (Math.max(0, Math.min(100, rhPercent)) / 100) * es_hPa * 100;
+}
+
+// Calculate air density from atmospheric conditions using ideal gas law
+export function computeAirDensity(pressurePa: number, temperatureC: number, rhPercent: number): number {
+  const e_Pa   = vaporPressurePa(temperatureC, rhPercent);
   const T      = (temperatureC + 273.15);
   const pd     = Math.max(0, pressurePa - e_Pa);
   const rho    = pd / (RD * T) + e_Pa / (RV * T);
@@ -45,3 +49,18 @@ export function getStandardConditions(): WeatherResult {
     obsTime: new Date().toISOString(),
   };
 }
+
+// Estimate station pressure (Pa) from GPS altitude using the ICAO standard
+// atmosphere lapse rate. Pass the local QNH (sea-level pressure) when known;
+// otherwise the standard 101325 Pa is assumed.
+export function pressureFromAltitude(altitudeM: number, seaLevelPa: number = 101325): number {
+  return seaLevelPa * Math.pow(1 - 2.25577e-5 * altitudeM, 5.25588);
+}
+
+// Temperature and pressure a height dh (m) above a station, following the
+// ICAO tropospheric lapse rate (6.5 K/km). Used for per-step density changes.
+export function atmosphereAtHeight(temperatureC: number, pressurePa: number, dh: number): { temperatureC: number; pressurePa: number } {
+  const T0 = temperatureC + 273.15;
+  const T = Math.max(1, T0 - 0.0065 * dh);
+  return { temperatureC: T - 273.15, pressurePa: pressurePa * Math.pow(T / T0, 5.25588) };
+}